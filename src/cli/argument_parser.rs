@@ -0,0 +1,374 @@
+//! Parsing of command-line arguments.
+
+use crate::cli::output::OutputFormat;
+use crate::markdown::reader::is_markdown_file;
+use crate::markdown::transform::Transform;
+use std::path::Path;
+
+/// Options collected from the command line.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CliOptions {
+    /// The markdown file to operate on.
+    pub path: String,
+    /// Print only fenced code blocks instead of the whole file.
+    pub extract_code: bool,
+    /// Restrict `--extract-code` to blocks with this language.
+    pub extract_language: Option<String>,
+    /// Markdown fragment file concatenated before the main content.
+    pub prepend: Option<String>,
+    /// Markdown fragment file concatenated after the main content.
+    pub append: Option<String>,
+    /// Transforms applied to the main content, in the given order.
+    pub transforms: Vec<Transform>,
+    /// Output format for the document content.
+    pub format: OutputFormat,
+    /// Print document statistics instead of the content.
+    pub stats: bool,
+    /// Print per-section word counts instead of the content.
+    pub words_per_heading: bool,
+    /// Print the stats delta between two files instead of reading one.
+    pub word_diff: Option<(String, String)>,
+    /// Print the heading slug collision summary instead of the content.
+    pub slug_collisions: bool,
+    /// Check that local link targets exist on disk.
+    pub check_links: bool,
+    /// Probe external http(s) links (needs the `external-links` feature).
+    pub check_external: bool,
+    /// Per-request timeout for `--check-external`, in seconds.
+    pub timeout_secs: Option<u64>,
+    /// Disable ANSI colors even when writing to a terminal.
+    pub no_color: bool,
+    /// Batch mode: treat the path as a directory and write transformed
+    /// files here, preserving the relative layout.
+    pub output_dir: Option<String>,
+    /// Print what a batch run would do instead of doing it.
+    pub dry_run: bool,
+    /// Print extra progress detail.
+    pub verbose: bool,
+    /// Suppress informational output (wins over `--verbose`).
+    pub quiet: bool,
+    /// Reprint the file whenever it changes on disk, until interrupted.
+    pub watch: bool,
+    /// Fail when the (frontmatter/code-excluded) word count is below this.
+    pub min_words: Option<usize>,
+    /// Fail when the (frontmatter/code-excluded) word count is above this.
+    pub max_words: Option<usize>,
+    /// Write the rendered output to this file instead of stdout.
+    pub output: Option<String>,
+    /// With `--output`, also print the output to stdout.
+    pub tee: bool,
+}
+
+/// What the command line asked the program to do.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    /// Print the help text and exit.
+    Help,
+    /// Print the version and exit.
+    Version,
+    /// Process a file with the given options (boxed: the options struct
+    /// is much larger than the other variants).
+    Run(Box<CliOptions>),
+}
+
+/// Parses the raw arguments (excluding the program name).
+///
+/// Returns the [`Action`] to take, or `Err` with a usage message for
+/// invalid invocations. `--help`/`-h` and `--version`/`-V` short-circuit
+/// the rest of the command line.
+pub fn parse_arguments(args: &[String]) -> Result<Action, String> {
+    let mut options = CliOptions::default();
+    let mut path: Option<String> = None;
+    let mut iter = args.iter().peekable();
+
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Action::Help),
+            "-V" | "--version" => return Ok(Action::Version),
+            "--extract-code" => {
+                options.extract_code = true;
+                // The language operand is optional: take the next argument
+                // unless it is another option or the markdown file itself.
+                if let Some(next) = iter.peek() {
+                    if !next.starts_with('-') && !is_markdown_file(Path::new(next.as_str())) {
+                        options.extract_language = iter.next().cloned();
+                    }
+                }
+            }
+            // Shorthand for `--format html`.
+            "--html" => options.format = OutputFormat::Html,
+            "--format" => {
+                let name = require_value(&mut iter, "--format")?;
+                options.format = OutputFormat::parse(&name)
+                    .ok_or_else(|| format!("unknown format: {name}"))?;
+            }
+            "--stats" => options.stats = true,
+            "--words-per-heading" => options.words_per_heading = true,
+            "--slug-collisions" => options.slug_collisions = true,
+            "--no-color" => options.no_color = true,
+            "--output" => {
+                options.output = Some(require_value(&mut iter, "--output")?);
+            }
+            "--tee" => options.tee = true,
+            "--output-dir" => {
+                options.output_dir = Some(require_value(&mut iter, "--output-dir")?);
+            }
+            "--dry-run" => options.dry_run = true,
+            "--watch" => options.watch = true,
+            "--min-words" => {
+                options.min_words = Some(require_count(&mut iter, "--min-words")?);
+            }
+            "--max-words" => {
+                options.max_words = Some(require_count(&mut iter, "--max-words")?);
+            }
+            "--verbose" => options.verbose = true,
+            "--quiet" => options.quiet = true,
+            "--check-links" => options.check_links = true,
+            "--check-external" => options.check_external = true,
+            "--timeout" => {
+                let value = require_value(&mut iter, "--timeout")?;
+                let seconds = value
+                    .parse::<u64>()
+                    .map_err(|_| format!("invalid --timeout value: {value}"))?;
+                options.timeout_secs = Some(seconds);
+            }
+            "--word-diff" => {
+                let old = require_value(&mut iter, "--word-diff")?;
+                let new = require_value(&mut iter, "--word-diff")?;
+                options.word_diff = Some((old, new));
+            }
+            "--prepend" => {
+                options.prepend = Some(require_value(&mut iter, "--prepend")?);
+            }
+            "--append" => {
+                options.append = Some(require_value(&mut iter, "--append")?);
+            }
+            "--transform" => {
+                let name = require_value(&mut iter, "--transform")?;
+                let transform = Transform::parse(&name)
+                    .ok_or_else(|| format!("unknown transform: {name}"))?;
+                options.transforms.push(transform);
+            }
+            other if other.starts_with('-') => {
+                return Err(format!("unknown option: {other}"));
+            }
+            other => {
+                if path.is_some() {
+                    return Err("expected exactly one file argument".to_string());
+                }
+                path = Some(other.to_string());
+            }
+        }
+    }
+
+    match path {
+        Some(path) => {
+            options.path = path;
+            Ok(Action::Run(Box::new(options)))
+        }
+        // `--word-diff` names both of its files itself.
+        None if options.word_diff.is_some() => Ok(Action::Run(Box::new(options))),
+        None => Err("missing file argument (try --help)".to_string()),
+    }
+}
+
+/// Pulls a numeric operand for an option.
+fn require_count(
+    iter: &mut std::iter::Peekable<std::slice::Iter<'_, String>>,
+    option: &str,
+) -> Result<usize, String> {
+    let value = require_value(iter, option)?;
+    value
+        .parse::<usize>()
+        .map_err(|_| format!("invalid {option} value: {value}"))
+}
+
+/// Pulls the value operand for an option, erroring if it is missing.
+fn require_value(
+    iter: &mut std::iter::Peekable<std::slice::Iter<'_, String>>,
+    option: &str,
+) -> Result<String, String> {
+    iter.next()
+        .cloned()
+        .ok_or_else(|| format!("{option} requires a value"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn parse(list: &[&str]) -> CliOptions {
+        match parse_arguments(&args(list)).unwrap() {
+            Action::Run(options) => *options,
+            action => panic!("expected a run action, got {action:?}"),
+        }
+    }
+
+    #[test]
+    fn parses_a_single_path() {
+        let options = parse(&["notes.md"]);
+        assert_eq!(options.path, "notes.md");
+        assert!(!options.extract_code);
+    }
+
+    #[test]
+    fn help_flag_yields_the_help_action() {
+        assert_eq!(parse_arguments(&args(&["--help"])).unwrap(), Action::Help);
+        assert_eq!(parse_arguments(&args(&["-h"])).unwrap(), Action::Help);
+    }
+
+    #[test]
+    fn version_flag_yields_the_version_action() {
+        assert_eq!(
+            parse_arguments(&args(&["--version"])).unwrap(),
+            Action::Version
+        );
+        assert_eq!(parse_arguments(&args(&["-V"])).unwrap(), Action::Version);
+        // Lower-case -v is not the version flag.
+        assert!(parse_arguments(&args(&["-v"])).is_err());
+    }
+
+    #[test]
+    fn extract_code_without_language() {
+        let options = parse(&["--extract-code", "notes.md"]);
+        assert!(options.extract_code);
+        assert_eq!(options.extract_language, None);
+    }
+
+    #[test]
+    fn extract_code_with_language() {
+        let options = parse(&["--extract-code", "rust", "notes.md"]);
+        assert!(options.extract_code);
+        assert_eq!(options.extract_language.as_deref(), Some("rust"));
+    }
+
+    #[test]
+    fn prepend_append_and_transforms() {
+        let options = parse(&[
+            "--prepend",
+            "header.md",
+            "--append",
+            "footer.md",
+            "--transform",
+            "uppercase",
+            "--transform",
+            "trim-trailing",
+            "notes.md",
+        ]);
+        assert_eq!(options.prepend.as_deref(), Some("header.md"));
+        assert_eq!(options.append.as_deref(), Some("footer.md"));
+        assert_eq!(
+            options.transforms,
+            vec![Transform::Uppercase, Transform::TrimTrailing]
+        );
+    }
+
+    #[test]
+    fn transform_requires_a_known_name() {
+        assert!(parse_arguments(&args(&["--transform", "rot13", "a.md"])).is_err());
+        assert!(parse_arguments(&args(&["--transform"])).is_err());
+    }
+
+    #[test]
+    fn html_flag_selects_the_html_format() {
+        assert_eq!(parse(&["--html", "notes.md"]).format, OutputFormat::Html);
+        assert_eq!(parse(&["notes.md"]).format, OutputFormat::Text);
+    }
+
+    #[test]
+    fn format_option_selects_a_named_format() {
+        assert_eq!(
+            parse(&["--format", "json", "notes.md"]).format,
+            OutputFormat::Json
+        );
+        assert!(parse_arguments(&args(&["--format", "yaml", "a.md"])).is_err());
+    }
+
+    #[test]
+    fn word_diff_takes_two_files_and_no_positional() {
+        let options = parse(&["--word-diff", "old.md", "new.md"]);
+        assert_eq!(
+            options.word_diff,
+            Some(("old.md".to_string(), "new.md".to_string()))
+        );
+        assert!(options.path.is_empty());
+    }
+
+    #[test]
+    fn word_diff_with_one_file_is_an_error() {
+        assert!(parse_arguments(&args(&["--word-diff", "old.md"])).is_err());
+    }
+
+    #[test]
+    fn words_per_heading_with_json_format() {
+        let options = parse(&["--words-per-heading", "--format", "json", "notes.md"]);
+        assert!(options.words_per_heading);
+        assert_eq!(options.format, OutputFormat::Json);
+    }
+
+    #[test]
+    fn batch_flags_are_recognized() {
+        let options = parse(&["--output-dir", "out", "--dry-run", "--verbose", "docs"]);
+        assert_eq!(options.output_dir.as_deref(), Some("out"));
+        assert!(options.dry_run);
+        assert!(options.verbose);
+    }
+
+    #[test]
+    fn output_and_tee_flags() {
+        let options = parse(&["--output", "out.md", "--tee", "notes.md"]);
+        assert_eq!(options.output.as_deref(), Some("out.md"));
+        assert!(options.tee);
+    }
+
+    #[test]
+    fn quiet_flag_is_recognized() {
+        assert!(parse(&["--quiet", "notes.md"]).quiet);
+    }
+
+    #[test]
+    fn word_budget_flags_take_counts() {
+        let options = parse(&["--min-words", "10", "--max-words", "500", "stub.md"]);
+        assert_eq!(options.min_words, Some(10));
+        assert_eq!(options.max_words, Some(500));
+        assert!(parse_arguments(&args(&["--min-words", "few", "a.md"])).is_err());
+    }
+
+    #[test]
+    fn watch_flag_is_recognized() {
+        assert!(parse(&["--watch", "guide.md"]).watch);
+    }
+
+    #[test]
+    fn no_color_flag_is_recognized() {
+        assert!(parse(&["--no-color", "notes.md"]).no_color);
+        assert!(!parse(&["notes.md"]).no_color);
+    }
+
+    #[test]
+    fn check_external_and_timeout() {
+        let options = parse(&["--check-external", "--timeout", "10", "notes.md"]);
+        assert!(options.check_external);
+        assert_eq!(options.timeout_secs, Some(10));
+        assert!(parse_arguments(&args(&["--timeout", "soon", "a.md"])).is_err());
+    }
+
+    #[test]
+    fn unknown_option_is_an_error() {
+        assert!(parse_arguments(&args(&["--bogus"])).is_err());
+    }
+
+    #[test]
+    fn missing_path_is_an_error() {
+        assert!(parse_arguments(&args(&[])).is_err());
+    }
+
+    #[test]
+    fn two_paths_are_an_error() {
+        assert!(parse_arguments(&args(&["a.md", "b.md"])).is_err());
+    }
+}
@@ -0,0 +1,169 @@
+//! Batch processing of a markdown directory into an output directory.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::markdown::error::{MarkdownError, MarkdownResult};
+use crate::markdown::reader::{self, is_markdown_file};
+use crate::markdown::transform::{self, Transform};
+
+/// One planned source → destination mapping in a batch run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlanEntry {
+    pub source: PathBuf,
+    pub destination: PathBuf,
+}
+
+/// Recursively enumerates the markdown files under `dir`, sorted so plan
+/// output and processing order are deterministic.
+pub fn enumerate_markdown_files(dir: &Path) -> MarkdownResult<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    collect_markdown_files(dir, &mut files)?;
+    files.sort();
+    Ok(files)
+}
+
+fn collect_markdown_files(dir: &Path, files: &mut Vec<PathBuf>) -> MarkdownResult<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_markdown_files(&path, files)?;
+        } else if is_markdown_file(&path) {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Maps every markdown file under `input_dir` to its destination under
+/// `output_dir`, preserving the relative directory structure. Only
+/// enumerates; nothing is read or written.
+pub fn plan_batch(input_dir: &Path, output_dir: &Path) -> MarkdownResult<Vec<PlanEntry>> {
+    let files = enumerate_markdown_files(input_dir)?;
+    let mut entries = Vec::with_capacity(files.len());
+    for source in files {
+        let relative = source
+            .strip_prefix(input_dir)
+            .map_err(|_| MarkdownError::NotAFile(source.display().to_string()))?;
+        entries.push(PlanEntry {
+            destination: output_dir.join(relative),
+            source,
+        });
+    }
+    Ok(entries)
+}
+
+/// Formats the dry-run plan: one `source -> destination` line per file,
+/// followed by the transform chain that would apply.
+pub fn format_plan(entries: &[PlanEntry], transforms: &[Transform]) -> String {
+    let chain = if transforms.is_empty() {
+        "(none)".to_string()
+    } else {
+        transforms
+            .iter()
+            .map(|t| t.name())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    let mut out = String::new();
+    for entry in entries {
+        out.push_str(&format!(
+            "{} -> {} [{chain}]\n",
+            entry.source.display(),
+            entry.destination.display()
+        ));
+    }
+    out.push_str(&format!(
+        "{} file(s), transforms: {chain}\n",
+        entries.len()
+    ));
+    out
+}
+
+/// Executes the batch: reads each source, applies the transforms, and
+/// writes the result to its destination, creating directories as needed.
+/// Returns the number of files written.
+pub fn run_batch(
+    input_dir: &Path,
+    output_dir: &Path,
+    transforms: &[Transform],
+) -> MarkdownResult<usize> {
+    let entries = plan_batch(input_dir, output_dir)?;
+    for entry in &entries {
+        let content = reader::read_markdown_file(&entry.source)?;
+        let transformed = transform::apply_all(transforms, &content);
+        if let Some(parent) = entry.destination.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&entry.destination, transformed).map_err(|source| {
+            MarkdownError::WriteError {
+                path: entry.destination.display().to_string(),
+                source,
+            }
+        })?;
+    }
+    Ok(entries.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a small input tree with nested markdown and one non-md file.
+    fn temp_tree(label: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!(
+            "ai_coding_agent_batch_{}_{label}",
+            std::process::id()
+        ));
+        fs::create_dir_all(root.join("sub")).unwrap();
+        fs::write(root.join("a.md"), "# A\n").unwrap();
+        fs::write(root.join("sub/b.md"), "# B\n").unwrap();
+        fs::write(root.join("notes.txt"), "not markdown").unwrap();
+        root
+    }
+
+    #[test]
+    fn plan_lists_each_file_with_destination_and_chain() {
+        let root = temp_tree("plan");
+        let out_dir = Path::new("/tmp/out");
+
+        let entries = plan_batch(&root, out_dir).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].source, root.join("a.md"));
+        assert_eq!(entries[0].destination, out_dir.join("a.md"));
+        assert_eq!(entries[1].destination, out_dir.join("sub/b.md"));
+
+        let plan = format_plan(&entries, &[Transform::Uppercase, Transform::TrimTrailing]);
+        assert!(plan.contains(&format!(
+            "{} -> {} [uppercase, trim-trailing]",
+            root.join("a.md").display(),
+            out_dir.join("a.md").display()
+        )));
+        assert!(plan.ends_with("2 file(s), transforms: uppercase, trim-trailing\n"));
+
+        fs::remove_dir_all(root).ok();
+    }
+
+    #[test]
+    fn empty_transform_chain_is_shown_as_none() {
+        let plan = format_plan(&[], &[]);
+        assert_eq!(plan, "0 file(s), transforms: (none)\n");
+    }
+
+    #[test]
+    fn run_batch_writes_transformed_files() {
+        let root = temp_tree("run");
+        let out_dir = root.join("out");
+
+        let written = run_batch(&root, &out_dir, &[Transform::Lowercase]).unwrap();
+        assert_eq!(written, 2);
+        assert_eq!(fs::read_to_string(out_dir.join("a.md")).unwrap(), "# a\n");
+        assert_eq!(
+            fs::read_to_string(out_dir.join("sub/b.md")).unwrap(),
+            "# b\n"
+        );
+
+        fs::remove_dir_all(root).ok();
+    }
+}
@@ -0,0 +1,89 @@
+//! ANSI coloring with TTY detection.
+//!
+//! All decisions about *whether* to color go through
+//! [`stdout_colors_enabled`]/[`stderr_colors_enabled`]; the actual
+//! wrapping is the pure [`colorize`] so it can be unit-tested without a
+//! real terminal.
+
+use std::io::IsTerminal;
+
+/// The semantic styles the tool uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    /// Red, for `Error:` lines.
+    Error,
+    /// Cyan, for `==>` file-header separators.
+    Header,
+    /// Yellow, for `Hint:` lines.
+    Hint,
+}
+
+impl Style {
+    fn code(&self) -> &'static str {
+        match self {
+            Style::Error => "31",
+            Style::Header => "36",
+            Style::Hint => "33",
+        }
+    }
+}
+
+/// Wraps `text` in the ANSI codes for `style` when `enabled`, and
+/// returns it untouched otherwise.
+pub fn colorize(text: &str, style: Style, enabled: bool) -> String {
+    if enabled {
+        format!("\x1b[{}m{text}\x1b[0m", style.code())
+    } else {
+        text.to_string()
+    }
+}
+
+/// Whether stdout output should be colored: the `--no-color` flag and
+/// the conventional `NO_COLOR` environment variable both disable it, as
+/// does stdout not being a terminal.
+pub fn stdout_colors_enabled(no_color_flag: bool) -> bool {
+    !no_color_flag && !no_color_env() && std::io::stdout().is_terminal()
+}
+
+/// Like [`stdout_colors_enabled`], but for stderr.
+pub fn stderr_colors_enabled(no_color_flag: bool) -> bool {
+    !no_color_flag && !no_color_env() && std::io::stderr().is_terminal()
+}
+
+/// `NO_COLOR` disables color when set to any value (https://no-color.org).
+fn no_color_env() -> bool {
+    std::env::var_os("NO_COLOR").is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn colorize_wraps_when_enabled() {
+        assert_eq!(
+            colorize("Error: boom", Style::Error, true),
+            "\x1b[31mError: boom\x1b[0m"
+        );
+        assert_eq!(
+            colorize("==> a.md <==", Style::Header, true),
+            "\x1b[36m==> a.md <==\x1b[0m"
+        );
+        assert_eq!(
+            colorize("Hint: try --help", Style::Hint, true),
+            "\x1b[33mHint: try --help\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn colorize_is_identity_when_disabled() {
+        assert_eq!(colorize("plain", Style::Error, false), "plain");
+    }
+
+    #[test]
+    fn the_flag_always_wins() {
+        // Regardless of TTY state or environment, --no-color disables.
+        assert!(!stdout_colors_enabled(true));
+        assert!(!stderr_colors_enabled(true));
+    }
+}
@@ -0,0 +1,141 @@
+//! Resolution of the effective output behavior from flags and environment.
+
+use std::io::IsTerminal;
+
+use crate::cli::argument_parser::CliOptions;
+
+/// The environment inputs that influence output behavior, captured once
+/// so the precedence rules are a pure function of this struct.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Env {
+    /// `NO_COLOR` is set (to any value).
+    pub no_color: bool,
+    /// `AI_CODING_AGENT_QUIET` is set.
+    pub quiet: bool,
+    /// `AI_CODING_AGENT_VERBOSE` is set.
+    pub verbose: bool,
+    /// stdout is attached to a terminal.
+    pub stdout_is_terminal: bool,
+}
+
+impl Env {
+    /// Reads the real process environment and terminal state.
+    pub fn capture() -> Self {
+        Env {
+            no_color: std::env::var_os("NO_COLOR").is_some(),
+            quiet: std::env::var_os("AI_CODING_AGENT_QUIET").is_some(),
+            verbose: std::env::var_os("AI_CODING_AGENT_VERBOSE").is_some(),
+            stdout_is_terminal: std::io::stdout().is_terminal(),
+        }
+    }
+}
+
+/// The resolved output behavior used throughout the program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Verbosity {
+    /// Color stdout output.
+    pub color: bool,
+    /// Suppress informational output (headers, progress summaries).
+    pub quiet: bool,
+    /// Print extra progress detail.
+    pub verbose: bool,
+}
+
+/// Resolves flags and environment into one [`Verbosity`].
+///
+/// Precedence, in order:
+/// 1. A flag or its env-var equivalent enables the setting; flags and
+///    env are equivalent (either suffices).
+/// 2. Quiet beats verbose: when both are requested, `verbose` is false.
+/// 3. Color requires a terminal on stdout and is disabled by either
+///    `--no-color` or `NO_COLOR`; quiet does not affect color.
+pub fn resolve_verbosity(opts: &CliOptions, env: &Env) -> Verbosity {
+    let quiet = opts.quiet || env.quiet;
+    let verbose = (opts.verbose || env.verbose) && !quiet;
+    let color = !opts.no_color && !env.no_color && env.stdout_is_terminal;
+    Verbosity {
+        color,
+        quiet,
+        verbose,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn opts(no_color: bool, quiet: bool, verbose: bool) -> CliOptions {
+        CliOptions {
+            no_color,
+            quiet,
+            verbose,
+            ..CliOptions::default()
+        }
+    }
+
+    fn env(no_color: bool, quiet: bool, verbose: bool, tty: bool) -> Env {
+        Env {
+            no_color,
+            quiet,
+            verbose,
+            stdout_is_terminal: tty,
+        }
+    }
+
+    #[test]
+    fn defaults_on_a_terminal() {
+        let v = resolve_verbosity(&opts(false, false, false), &env(false, false, false, true));
+        assert_eq!(
+            v,
+            Verbosity {
+                color: true,
+                quiet: false,
+                verbose: false
+            }
+        );
+    }
+
+    #[test]
+    fn color_needs_a_terminal() {
+        let v = resolve_verbosity(&opts(false, false, false), &env(false, false, false, false));
+        assert!(!v.color);
+    }
+
+    #[test]
+    fn no_color_flag_and_env_each_disable_color() {
+        let tty = env(false, false, false, true);
+        assert!(!resolve_verbosity(&opts(true, false, false), &tty).color);
+        let no_color_env = env(true, false, false, true);
+        assert!(!resolve_verbosity(&opts(false, false, false), &no_color_env).color);
+    }
+
+    #[test]
+    fn quiet_and_verbose_come_from_flag_or_env() {
+        let plain = env(false, false, false, false);
+        assert!(resolve_verbosity(&opts(false, true, false), &plain).quiet);
+        assert!(resolve_verbosity(&opts(false, false, true), &plain).verbose);
+        assert!(resolve_verbosity(&opts(false, false, false), &env(false, true, false, false)).quiet);
+        assert!(
+            resolve_verbosity(&opts(false, false, false), &env(false, false, true, false)).verbose
+        );
+    }
+
+    #[test]
+    fn quiet_beats_verbose() {
+        let plain = env(false, false, false, false);
+        let v = resolve_verbosity(&opts(false, true, true), &plain);
+        assert!(v.quiet);
+        assert!(!v.verbose);
+        // Also across sources: quiet flag vs verbose env, and vice versa.
+        let v = resolve_verbosity(&opts(false, true, false), &env(false, false, true, false));
+        assert!(!v.verbose);
+        let v = resolve_verbosity(&opts(false, false, true), &env(false, true, false, false));
+        assert!(v.quiet && !v.verbose);
+    }
+
+    #[test]
+    fn quiet_does_not_disable_color() {
+        let v = resolve_verbosity(&opts(false, true, false), &env(false, false, false, true));
+        assert!(v.color);
+    }
+}
@@ -0,0 +1,54 @@
+//! Help text for the command-line interface.
+
+/// Prints the usage message to stdout.
+pub fn display_help() {
+    println!(
+        "ai_coding_agent v{} - read and inspect markdown files",
+        env!("CARGO_PKG_VERSION")
+    );
+    println!();
+    println!("Usage: ai_coding_agent [OPTIONS] <FILE>");
+    println!();
+    println!("Arguments:");
+    println!("  <FILE>        Path to a markdown file (.md or .markdown)");
+    println!();
+    println!("Options:");
+    println!("  --extract-code [LANG]  Print only fenced code blocks, optionally");
+    println!("                         restricted to blocks tagged with LANG");
+    println!("  --format <FORMAT>      Output format: text (default), plain, html, json");
+    println!("  --html                 Shorthand for --format html");
+    println!("  --no-color             Disable ANSI colors (NO_COLOR is also honored)");
+    println!("  --output <FILE>        Write the rendered output to FILE instead of stdout");
+    println!("  --tee                  With --output, also print the output to stdout");
+    println!("  --output-dir <DIR>     Batch mode: process every markdown file under the");
+    println!("                         given directory, writing results into DIR");
+    println!("  --dry-run              With --output-dir, print the plan instead of writing");
+    println!("  --quiet                Suppress informational output (wins over --verbose)");
+    println!("  --verbose              Print extra progress detail");
+    println!("  --watch                Reprint the file whenever it changes (Ctrl-C stops)");
+    println!("  --check-links          Report local link targets that do not exist;");
+    println!("                         exits non-zero if any are broken");
+    println!("  --check-external       Probe external http links with HEAD requests");
+    println!("                         (requires the external-links build feature)");
+    println!("  --timeout <SECS>       Per-request timeout for --check-external (default 5)");
+    println!("  --slug-collisions      Print the heading slug collision summary");
+    println!("  --min-words <N>        Fail if the word count (excluding frontmatter and");
+    println!("                         code fences) is below N");
+    println!("  --max-words <N>        Fail if that word count is above N");
+    println!("  --stats                Print line/word/char/heading/code-block counts");
+    println!("  --words-per-heading    Print word counts per heading-delimited section");
+    println!("                         (as JSON with --format json)");
+    println!("  --word-diff <OLD> <NEW>");
+    println!("                         Print the word/char/line delta between two files");
+    println!("  --prepend <FILE>       Concatenate a markdown fragment before the content");
+    println!("  --append <FILE>        Concatenate a markdown fragment after the content");
+    println!("  --transform <NAME>     Apply a transform to the main content (repeatable);");
+    println!("                         one of: uppercase, lowercase, trim-trailing");
+    println!("  -h, --help             Print this help message");
+    println!("  -V, --version          Print the version");
+}
+
+/// Prints the version, kept in sync with Cargo.toml at compile time.
+pub fn display_version() {
+    println!("ai_coding_agent {}", env!("CARGO_PKG_VERSION"));
+}
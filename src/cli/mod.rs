@@ -0,0 +1,9 @@
+//! Command-line front end: argument parsing and help output.
+
+pub mod argument_parser;
+pub mod batch;
+pub mod color;
+pub mod config;
+pub mod help;
+pub mod output;
+pub mod watch;
@@ -0,0 +1,252 @@
+//! Output formats and the pluggable [`Renderer`] extension point.
+
+use std::io::Write;
+use std::path::Path;
+
+use crate::markdown::error::{MarkdownError, MarkdownResult};
+use crate::markdown::render;
+use crate::markdown::stats::SectionWords;
+
+/// The built-in output formats selectable with `--format`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Raw markdown content, unchanged.
+    #[default]
+    Text,
+    /// Markdown with formatting stripped.
+    Plain,
+    /// The HTML rendering from [`render::to_html`].
+    Html,
+    /// A JSON object wrapping the content.
+    Json,
+}
+
+impl OutputFormat {
+    /// Parses a format from its command-line name.
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "text" => Some(OutputFormat::Text),
+            "plain" => Some(OutputFormat::Plain),
+            "html" => Some(OutputFormat::Html),
+            "json" => Some(OutputFormat::Json),
+            _ => None,
+        }
+    }
+
+    /// Returns the built-in renderer for this format.
+    pub fn renderer(&self) -> Box<dyn Renderer> {
+        match self {
+            OutputFormat::Text => Box::new(TextRenderer),
+            OutputFormat::Plain => Box::new(PlainRenderer),
+            OutputFormat::Html => Box::new(HtmlRenderer),
+            OutputFormat::Json => Box::new(JsonRenderer),
+        }
+    }
+}
+
+/// Turns assembled markdown content into the final output string.
+///
+/// Implement this to plug a custom output format into the pipeline
+/// without touching the dispatch in `main`.
+pub trait Renderer {
+    fn render(&self, content: &str) -> MarkdownResult<String>;
+}
+
+/// Passes the markdown through unchanged.
+pub struct TextRenderer;
+
+impl Renderer for TextRenderer {
+    fn render(&self, content: &str) -> MarkdownResult<String> {
+        Ok(content.to_string())
+    }
+}
+
+/// Strips markdown formatting via [`render::to_plain`].
+pub struct PlainRenderer;
+
+impl Renderer for PlainRenderer {
+    fn render(&self, content: &str) -> MarkdownResult<String> {
+        Ok(render::to_plain(content))
+    }
+}
+
+/// Renders HTML via [`render::to_html`].
+pub struct HtmlRenderer;
+
+impl Renderer for HtmlRenderer {
+    fn render(&self, content: &str) -> MarkdownResult<String> {
+        Ok(render::to_html(content))
+    }
+}
+
+/// Wraps the content in a `{"content": ...}` JSON object.
+pub struct JsonRenderer;
+
+impl Renderer for JsonRenderer {
+    fn render(&self, content: &str) -> MarkdownResult<String> {
+        Ok(format!("{{\"content\": {}}}\n", json_string(content)))
+    }
+}
+
+/// Delivers rendered output to its destinations.
+///
+/// With no output file the content goes to `stdout` only. With an
+/// output file it is written there; `tee` additionally prints it to
+/// `stdout` first, so the content is still visible even when the file
+/// write fails afterwards.
+pub fn write_output(
+    content: &str,
+    output_path: Option<&Path>,
+    tee: bool,
+    stdout: &mut impl Write,
+) -> MarkdownResult<()> {
+    let Some(path) = output_path else {
+        stdout.write_all(content.as_bytes())?;
+        return Ok(());
+    };
+    if tee {
+        stdout.write_all(content.as_bytes())?;
+        stdout.flush()?;
+    }
+    std::fs::write(path, content).map_err(|source| MarkdownError::WriteError {
+        path: path.display().to_string(),
+        source,
+    })
+}
+
+/// Serializes per-section word counts as a JSON array of
+/// `{"section": ..., "level": ..., "words": ...}` objects.
+pub fn sections_to_json(sections: &[SectionWords]) -> String {
+    let objects: Vec<String> = sections
+        .iter()
+        .map(|section| {
+            format!(
+                "{{\"section\":{},\"level\":{},\"words\":{}}}",
+                json_string(&section.section),
+                section.level,
+                section.words
+            )
+        })
+        .collect();
+    format!("[{}]\n", objects.join(","))
+}
+
+/// Encodes `text` as a JSON string literal.
+pub(crate) fn json_string(text: &str) -> String {
+    let mut out = String::with_capacity(text.len() + 2);
+    out.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_parsing_covers_all_builtins() {
+        assert_eq!(OutputFormat::parse("text"), Some(OutputFormat::Text));
+        assert_eq!(OutputFormat::parse("plain"), Some(OutputFormat::Plain));
+        assert_eq!(OutputFormat::parse("html"), Some(OutputFormat::Html));
+        assert_eq!(OutputFormat::parse("json"), Some(OutputFormat::Json));
+        assert_eq!(OutputFormat::parse("yaml"), None);
+    }
+
+    #[test]
+    fn builtin_renderers_dispatch_by_format() {
+        let content = "# Hi\n";
+        assert_eq!(
+            OutputFormat::Text.renderer().render(content).unwrap(),
+            "# Hi\n"
+        );
+        assert_eq!(
+            OutputFormat::Plain.renderer().render(content).unwrap(),
+            "Hi\n"
+        );
+        assert_eq!(
+            OutputFormat::Html.renderer().render(content).unwrap(),
+            "<h1>Hi</h1>\n"
+        );
+        assert_eq!(
+            OutputFormat::Json.renderer().render(content).unwrap(),
+            "{\"content\": \"# Hi\\n\"}\n"
+        );
+    }
+
+    #[test]
+    fn json_string_escapes_specials() {
+        assert_eq!(json_string("a\"b\\c\nd"), "\"a\\\"b\\\\c\\nd\"");
+    }
+
+    #[test]
+    fn sections_serialize_with_names_levels_and_counts() {
+        use crate::markdown::stats::words_per_section;
+
+        let content = "preamble words\n\n## Intro\n\none two\n";
+        let json = sections_to_json(&words_per_section(content));
+        assert_eq!(
+            json,
+            "[{\"section\":\"(preamble)\",\"level\":0,\"words\":2},\
+             {\"section\":\"Intro\",\"level\":2,\"words\":2}]\n"
+        );
+    }
+
+    #[test]
+    fn tee_writes_both_the_file_and_stdout() {
+        let path = std::env::temp_dir().join(format!(
+            "ai_coding_agent_tee_{}.md",
+            std::process::id()
+        ));
+        let mut stdout = Vec::new();
+        write_output("# Both\n", Some(&path), true, &mut stdout).unwrap();
+        assert_eq!(stdout, b"# Both\n");
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "# Both\n");
+        std::fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn output_file_without_tee_skips_stdout() {
+        let path = std::env::temp_dir().join(format!(
+            "ai_coding_agent_notee_{}.md",
+            std::process::id()
+        ));
+        let mut stdout = Vec::new();
+        write_output("# File only\n", Some(&path), false, &mut stdout).unwrap();
+        assert!(stdout.is_empty());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "# File only\n");
+        std::fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn tee_still_prints_when_the_file_write_fails() {
+        let mut stdout = Vec::new();
+        let bad = Path::new("/no/such/dir/out.md");
+        let err = write_output("content\n", Some(bad), true, &mut stdout).unwrap_err();
+        assert!(matches!(err, MarkdownError::WriteError { .. }));
+        assert_eq!(stdout, b"content\n");
+    }
+
+    #[test]
+    fn custom_renderer_plugs_into_the_dispatch() {
+        struct Shouting;
+        impl Renderer for Shouting {
+            fn render(&self, content: &str) -> MarkdownResult<String> {
+                Ok(content.to_uppercase())
+            }
+        }
+
+        let renderer: Box<dyn Renderer> = Box::new(Shouting);
+        assert_eq!(renderer.render("quiet").unwrap(), "QUIET");
+    }
+}
@@ -0,0 +1,101 @@
+//! Watch mode: reprint a file whenever it changes on disk.
+
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::{Duration, SystemTime};
+
+use crate::markdown::error::MarkdownResult;
+use crate::markdown::reader;
+
+/// How often the watcher polls the file's modification time.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// One poll step: reports `Some(mtime)` when the file exists and its
+/// modification time differs from `last_seen`, meaning the content
+/// should be reprinted. A missing file (e.g. mid write-rename) yields
+/// `None` so the caller just keeps waiting.
+pub fn watch_once(path: &Path, last_seen: Option<SystemTime>) -> Option<SystemTime> {
+    let mtime = std::fs::metadata(path).ok()?.modified().ok()?;
+    if last_seen != Some(mtime) {
+        Some(mtime)
+    } else {
+        None
+    }
+}
+
+/// Validates and prints the file, then polls until interrupted,
+/// clearing the screen and reprinting on every change. Editors that
+/// replace the file via write-rename make it briefly disappear; the
+/// loop simply resumes when it reappears.
+pub fn watch(path: &str) -> MarkdownResult<ExitCode> {
+    let content = reader::read_markdown_file(path)?;
+    print!("{content}");
+    let mut last_seen = watch_once(Path::new(path), None);
+
+    loop {
+        std::thread::sleep(POLL_INTERVAL);
+        if let Some(mtime) = watch_once(Path::new(path), last_seen) {
+            last_seen = Some(mtime);
+            // A read can still fail transiently mid-save; skip this
+            // round and pick the content up on the next change.
+            if let Ok(content) = reader::read_markdown_file(path) {
+                print!("\x1b[2J\x1b[H{content}");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::PathBuf;
+
+    fn temp_file(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "ai_coding_agent_watch_{}_{name}",
+            std::process::id()
+        ));
+        fs::write(&path, "# Watched\n").unwrap();
+        path
+    }
+
+    #[test]
+    fn first_observation_reports_a_change() {
+        let path = temp_file("first.md");
+        assert!(watch_once(&path, None).is_some());
+        fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn unchanged_mtime_reports_nothing() {
+        let path = temp_file("unchanged.md");
+        let seen = watch_once(&path, None);
+        assert_eq!(watch_once(&path, seen), None);
+        fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn differing_mtimes_report_a_change() {
+        let old = temp_file("old.md");
+        let new = temp_file("new.md");
+        // Give the two files distinct mtimes without sleeping.
+        let past = SystemTime::now() - Duration::from_secs(60);
+        fs::File::open(&old)
+            .and_then(|f| f.set_modified(past))
+            .unwrap();
+
+        let seen_old = watch_once(&old, None);
+        assert!(seen_old.is_some());
+        // The mtime of a different (newer) file counts as a change.
+        assert!(watch_once(&new, seen_old).is_some());
+
+        fs::remove_file(old).ok();
+        fs::remove_file(new).ok();
+    }
+
+    #[test]
+    fn missing_file_reports_nothing() {
+        assert_eq!(watch_once(Path::new("/no/such/file.md"), None), None);
+    }
+}
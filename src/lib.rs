@@ -0,0 +1,8 @@
+//! Library entry point for the `ai_coding_agent` markdown tool.
+//!
+//! The functionality lives in two trees: [`markdown`] holds the
+//! file-format logic (reading, validation, errors) and [`cli`] holds
+//! everything related to the command-line front end.
+
+pub mod cli;
+pub mod markdown;
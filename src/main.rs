@@ -0,0 +1,276 @@
+use std::process::ExitCode;
+
+use ai_coding_agent::cli::argument_parser::{self, CliOptions};
+use ai_coding_agent::cli::color::{self, Style};
+use ai_coding_agent::cli::output::OutputFormat;
+use ai_coding_agent::cli::config::{self, Verbosity};
+use ai_coding_agent::cli::{batch, help, output, watch};
+use ai_coding_agent::markdown::{code, links, reader, stats, toc, transform};
+use std::path::Path;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let options = match argument_parser::parse_arguments(&args) {
+        Ok(argument_parser::Action::Run(options)) => *options,
+        Ok(argument_parser::Action::Help) => {
+            help::display_help();
+            return ExitCode::SUCCESS;
+        }
+        Ok(argument_parser::Action::Version) => {
+            help::display_version();
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            print_error(&format!("Error: {message}"), false);
+            eprintln!(
+                "{}",
+                color::colorize(
+                    "Hint: try --help",
+                    Style::Hint,
+                    color::stderr_colors_enabled(false)
+                )
+            );
+            return ExitCode::from(2);
+        }
+    };
+
+    let verbosity = config::resolve_verbosity(&options, &config::Env::capture());
+    match run(&options, verbosity) {
+        Ok(code) => code,
+        Err(err) => {
+            print_error(&format!("Error: {err}"), options.no_color);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Prints an `Error:` line to stderr, colored when appropriate.
+fn print_error(message: &str, no_color: bool) {
+    eprintln!(
+        "{}",
+        color::colorize(message, Style::Error, color::stderr_colors_enabled(no_color))
+    );
+}
+
+fn run(
+    options: &CliOptions,
+    verbosity: Verbosity,
+) -> ai_coding_agent::markdown::error::MarkdownResult<ExitCode> {
+    if let Some((old_path, new_path)) = &options.word_diff {
+        let old = stats::compute_stats(&reader::read_markdown_file(old_path)?);
+        let new = stats::compute_stats(&reader::read_markdown_file(new_path)?);
+        println!("{}", stats::diff_stats(&old, &new));
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if options.watch {
+        return watch::watch(&options.path);
+    }
+
+    if let Some(output_dir) = &options.output_dir {
+        let input_dir = Path::new(&options.path);
+        let output_dir = Path::new(output_dir);
+        if options.dry_run {
+            let entries = batch::plan_batch(input_dir, output_dir)?;
+            print!("{}", batch::format_plan(&entries, &options.transforms));
+        } else {
+            let written = batch::run_batch(input_dir, output_dir, &options.transforms)?;
+            if verbosity.verbose {
+                println!("wrote {written} file(s) to {}", output_dir.display());
+            }
+        }
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let content = reader::read_markdown_file(&options.path)?;
+
+    if options.extract_code {
+        print_code_blocks(&content, options.extract_language.as_deref());
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if options.min_words.is_some() || options.max_words.is_some() {
+        let words = stats::count_words(&content);
+        if let Some(min) = options.min_words {
+            if words < min {
+                print_error(
+                    &format!(
+                        "Error: {} has {words} words, below the minimum of {min}",
+                        options.path
+                    ),
+                    options.no_color,
+                );
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+        if let Some(max) = options.max_words {
+            if words > max {
+                print_error(
+                    &format!(
+                        "Error: {} has {words} words, above the maximum of {max}",
+                        options.path
+                    ),
+                    options.no_color,
+                );
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if options.stats {
+        print_stats(&stats::compute_stats(&content));
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if options.check_external {
+        return check_external(&content, options.timeout_secs.unwrap_or(5));
+    }
+
+    if options.check_links {
+        let base_dir = Path::new(&options.path)
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .unwrap_or_else(|| Path::new("."))
+            .to_path_buf();
+        let broken = links::check_local_links(&base_dir, &links::extract_links(&content));
+        for item in &broken {
+            println!(
+                "{}:{}: broken link [{}]({}) -> {}",
+                options.path,
+                item.link.line,
+                item.link.text,
+                item.link.target,
+                item.resolved.display()
+            );
+        }
+        return Ok(if broken.is_empty() {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::FAILURE
+        });
+    }
+
+    if options.words_per_heading {
+        let sections = stats::words_per_section(&content);
+        if options.format == OutputFormat::Json {
+            print!("{}", output::sections_to_json(&sections));
+        } else {
+            for section in &sections {
+                println!("{} (level {}): {}", section.section, section.level, section.words);
+            }
+        }
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if options.slug_collisions {
+        println!("slug collisions: {}", toc::slug_collision_count(&content));
+        for (slug, count) in toc::slug_collisions(&content) {
+            println!("  {slug} ({count} headings)");
+        }
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    // Transforms apply to the main content only; prepend/append fragments
+    // are attached verbatim afterwards.
+    let content = transform::apply_all(&options.transforms, &content);
+    let prepend = read_fragment(options.prepend.as_deref())?;
+    let append = read_fragment(options.append.as_deref())?;
+    let document =
+        transform::compose_document(prepend.as_deref(), &content, append.as_deref());
+
+    let renderer: Box<dyn output::Renderer> = options.format.renderer();
+    let rendered = renderer.render(&document)?;
+
+    // The `==>` header only makes sense for raw text output going to
+    // the terminal, and is informational, so `--quiet` drops it.
+    let to_stdout = options.output.is_none() || options.tee;
+    if options.format == OutputFormat::Text && !verbosity.quiet && to_stdout {
+        println!(
+            "{}",
+            color::colorize(
+                &format!("==> {} <==", options.path),
+                Style::Header,
+                verbosity.color
+            )
+        );
+    }
+    output::write_output(
+        &rendered,
+        options.output.as_deref().map(Path::new),
+        options.tee,
+        &mut std::io::stdout(),
+    )?;
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Probes external links, printing one status line per link.
+#[cfg(feature = "external-links")]
+fn check_external(
+    content: &str,
+    timeout_secs: u64,
+) -> ai_coding_agent::markdown::error::MarkdownResult<ExitCode> {
+    let timeout = std::time::Duration::from_secs(timeout_secs);
+    let mut failed = false;
+    for (link, status) in links::check_external_links(content, timeout) {
+        let label = match &status {
+            links::ExternalStatus::Status(code) => format!("HTTP {code}"),
+            links::ExternalStatus::Error(reason) => format!("error: {reason}"),
+        };
+        println!("{}: {} -> {label}", link.line, link.target);
+        failed |= !status.is_ok();
+    }
+    Ok(if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+#[cfg(not(feature = "external-links"))]
+fn check_external(
+    _content: &str,
+    _timeout_secs: u64,
+) -> ai_coding_agent::markdown::error::MarkdownResult<ExitCode> {
+    eprintln!("Error: --check-external requires building with --features external-links");
+    Ok(ExitCode::FAILURE)
+}
+
+/// Reads an optional prepend/append fragment file.
+fn read_fragment(
+    path: Option<&str>,
+) -> ai_coding_agent::markdown::error::MarkdownResult<Option<String>> {
+    path.map(reader::read_markdown_file).transpose()
+}
+
+/// Prints document statistics as aligned `key: value` lines.
+fn print_stats(stats: &stats::DocStats) {
+    println!("lines:       {}", stats.lines);
+    println!("words:       {}", stats.words);
+    println!("chars:       {}", stats.chars);
+    println!("headings:    {}", stats.headings);
+    println!("code_blocks: {}", stats.code_blocks);
+}
+
+/// Prints matching code blocks, separated by blank lines.
+fn print_code_blocks(content: &str, language: Option<&str>) {
+    let blocks = code::extract_code_blocks(content);
+    let mut first = true;
+    for block in &blocks {
+        if let Some(wanted) = language {
+            let matches = block
+                .language
+                .as_deref()
+                .is_some_and(|lang| lang.eq_ignore_ascii_case(wanted));
+            if !matches {
+                continue;
+            }
+        }
+        if !first {
+            println!();
+        }
+        print!("{}", block.code);
+        first = false;
+    }
+}
@@ -0,0 +1,228 @@
+//! Extraction of fenced code blocks from markdown content.
+
+/// A fenced code block found in a markdown document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodeBlock {
+    /// Language taken from the info string, if one was given.
+    pub language: Option<String>,
+    /// The code between the fences, without the fence lines.
+    pub code: String,
+    /// 1-based line number of the opening fence.
+    pub start_line: usize,
+}
+
+/// How a line relates to fenced code, as reported by [`FenceTracker`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FenceEvent {
+    /// The line opens a fence; carries the language from the info string.
+    Opened(Option<String>),
+    /// The line closes the current fence.
+    Closed,
+    /// The line is code inside an open fence.
+    Inside,
+    /// The line is ordinary markdown outside any fence.
+    Outside,
+}
+
+/// Line-by-line fence state machine shared by every fence-aware scanner.
+///
+/// Both ``` and ~~~ fences are recognized. Per the CommonMark
+/// fence-length rules, a block is only closed by a fence using the same
+/// marker character that is at least as long as the opening fence, so a
+/// longer fence can wrap shorter fence lines as literal content.
+#[derive(Debug, Default)]
+pub struct FenceTracker {
+    open: Option<(char, usize)>,
+}
+
+impl FenceTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` while inside an open fence.
+    pub fn in_fence(&self) -> bool {
+        self.open.is_some()
+    }
+
+    /// Feeds the next line and reports how it relates to fenced code.
+    pub fn observe(&mut self, line: &str) -> FenceEvent {
+        match self.open {
+            Some((marker, length)) => {
+                if is_closing_fence(line, marker, length) {
+                    self.open = None;
+                    FenceEvent::Closed
+                } else {
+                    FenceEvent::Inside
+                }
+            }
+            None => match parse_opening_fence(line) {
+                Some((marker, length, language)) => {
+                    self.open = Some((marker, length));
+                    FenceEvent::Opened(language)
+                }
+                None => FenceEvent::Outside,
+            },
+        }
+    }
+}
+
+/// Extracts every fenced code block from `content`.
+///
+/// An unterminated fence runs to the end of the document. See
+/// [`FenceTracker`] for the fence rules.
+pub fn extract_code_blocks(content: &str) -> Vec<CodeBlock> {
+    let mut blocks = Vec::new();
+    let mut tracker = FenceTracker::new();
+    let mut current: Option<(Option<String>, usize, Vec<String>)> = None;
+
+    for (index, line) in content.lines().enumerate() {
+        match tracker.observe(line) {
+            FenceEvent::Opened(language) => {
+                current = Some((language, index + 1, Vec::new()));
+            }
+            FenceEvent::Inside => {
+                if let Some((_, _, lines)) = current.as_mut() {
+                    lines.push(line.to_string());
+                }
+            }
+            FenceEvent::Closed => {
+                if let Some((language, start_line, lines)) = current.take() {
+                    blocks.push(CodeBlock {
+                        language,
+                        code: join_lines(&lines),
+                        start_line,
+                    });
+                }
+            }
+            FenceEvent::Outside => {}
+        }
+    }
+
+    if let Some((language, start_line, lines)) = current {
+        blocks.push(CodeBlock {
+            language,
+            code: join_lines(&lines),
+            start_line,
+        });
+    }
+
+    blocks
+}
+
+fn join_lines(lines: &[String]) -> String {
+    let mut code = lines.join("\n");
+    if !lines.is_empty() {
+        code.push('\n');
+    }
+    code
+}
+
+/// Parses a fence opener: at least three ` or ~ after up to three spaces
+/// of indentation, returning the marker, fence length, and language.
+fn parse_opening_fence(line: &str) -> Option<(char, usize, Option<String>)> {
+    let indent = line.len() - line.trim_start_matches(' ').len();
+    if indent > 3 {
+        return None;
+    }
+    let rest = &line[indent..];
+    let marker = rest.chars().next()?;
+    if marker != '`' && marker != '~' {
+        return None;
+    }
+    let length = rest.chars().take_while(|&c| c == marker).count();
+    if length < 3 {
+        return None;
+    }
+    let info = rest[length..].trim();
+    // CommonMark forbids backticks in the info string of a backtick fence.
+    if marker == '`' && info.contains('`') {
+        return None;
+    }
+    let language = info
+        .split_whitespace()
+        .next()
+        .map(|token| token.to_string());
+    Some((marker, length, language))
+}
+
+/// A closing fence repeats the opening marker at least `min_length` times
+/// with nothing else on the line but whitespace.
+fn is_closing_fence(line: &str, marker: char, min_length: usize) -> bool {
+    let trimmed = line.trim();
+    let length = trimmed.chars().take_while(|&c| c == marker).count();
+    length >= min_length && trimmed.chars().all(|c| c == marker)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_a_backtick_block_with_language() {
+        let content = "# Title\n\n```rust\nfn main() {}\n```\n";
+        let blocks = extract_code_blocks(content);
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].language.as_deref(), Some("rust"));
+        assert_eq!(blocks[0].code, "fn main() {}\n");
+        assert_eq!(blocks[0].start_line, 3);
+    }
+
+    #[test]
+    fn extracts_a_tilde_block_without_language() {
+        let content = "~~~\nplain\n~~~\n";
+        let blocks = extract_code_blocks(content);
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].language, None);
+        assert_eq!(blocks[0].code, "plain\n");
+    }
+
+    #[test]
+    fn closing_fence_must_match_marker() {
+        let content = "```\ncode\n~~~\nmore\n```\n";
+        let blocks = extract_code_blocks(content);
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].code, "code\n~~~\nmore\n");
+    }
+
+    #[test]
+    fn longer_fence_wraps_shorter_fences() {
+        let content = "````markdown\n```rust\nfn f() {}\n```\n````\n";
+        let blocks = extract_code_blocks(content);
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].language.as_deref(), Some("markdown"));
+        assert_eq!(blocks[0].code, "```rust\nfn f() {}\n```\n");
+    }
+
+    #[test]
+    fn closing_fence_may_be_longer_than_opening() {
+        let content = "```\ncode\n`````\n";
+        let blocks = extract_code_blocks(content);
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].code, "code\n");
+    }
+
+    #[test]
+    fn unterminated_fence_runs_to_eof() {
+        let content = "```sh\necho hi";
+        let blocks = extract_code_blocks(content);
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].code, "echo hi\n");
+    }
+
+    #[test]
+    fn two_short_fences_are_not_an_opener() {
+        let content = "``\nnot code\n``\n";
+        assert!(extract_code_blocks(content).is_empty());
+    }
+
+    #[test]
+    fn multiple_blocks_keep_their_order_and_lines() {
+        let content = "```rust\na\n```\ntext\n```python\nb\n```\n";
+        let blocks = extract_code_blocks(content);
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].language.as_deref(), Some("rust"));
+        assert_eq!(blocks[1].language.as_deref(), Some("python"));
+        assert_eq!(blocks[1].start_line, 5);
+    }
+}
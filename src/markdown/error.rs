@@ -0,0 +1,150 @@
+//! Error types for markdown file handling.
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+/// Convenience alias used throughout the crate.
+pub type MarkdownResult<T> = Result<T, MarkdownError>;
+
+/// Everything that can go wrong while locating and reading a markdown file.
+#[derive(Debug)]
+pub enum MarkdownError {
+    /// The path does not exist on disk.
+    FileNotFound(String),
+    /// The path exists but is a directory or other non-regular file.
+    NotAFile(String),
+    /// The file does not carry a markdown extension (`.md` / `.markdown`).
+    InvalidExtension(String),
+    /// The file exceeds the size limit enforced by the reader.
+    FileTooLarge {
+        path: String,
+        size: u64,
+        limit: u64,
+    },
+    /// The file contents are not valid UTF-8.
+    InvalidUtf8(String),
+    /// An I/O error occurred while reading a specific file.
+    ReadError { path: String, source: io::Error },
+    /// An I/O error occurred while writing a specific file.
+    WriteError { path: String, source: io::Error },
+    /// A general I/O error not tied to a single file.
+    IoError(io::Error),
+}
+
+impl MarkdownError {
+    /// Returns `true` if retrying the operation might succeed.
+    ///
+    /// Transient I/O conditions (interrupted syscalls, timeouts,
+    /// would-block) are recoverable; structural problems with the path or
+    /// file (missing, wrong extension, too large, bad encoding) are not.
+    /// Callers can use this to build their own retry or skip policies.
+    pub fn is_recoverable(&self) -> bool {
+        match self {
+            MarkdownError::ReadError { source, .. }
+            | MarkdownError::WriteError { source, .. }
+            | MarkdownError::IoError(source) => {
+                matches!(
+                    source.kind(),
+                    io::ErrorKind::Interrupted
+                        | io::ErrorKind::TimedOut
+                        | io::ErrorKind::WouldBlock
+                )
+            }
+            MarkdownError::FileNotFound(_)
+            | MarkdownError::NotAFile(_)
+            | MarkdownError::InvalidExtension(_)
+            | MarkdownError::FileTooLarge { .. }
+            | MarkdownError::InvalidUtf8(_) => false,
+        }
+    }
+}
+
+impl fmt::Display for MarkdownError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MarkdownError::FileNotFound(path) => write!(f, "file not found: {path}"),
+            MarkdownError::NotAFile(path) => write!(f, "not a regular file: {path}"),
+            MarkdownError::InvalidExtension(path) => {
+                write!(f, "not a markdown file (expected .md or .markdown): {path}")
+            }
+            MarkdownError::FileTooLarge { path, size, limit } => {
+                write!(f, "file too large: {path} ({size} bytes, limit {limit})")
+            }
+            MarkdownError::InvalidUtf8(path) => {
+                write!(f, "file is not valid UTF-8: {path}")
+            }
+            MarkdownError::ReadError { path, source } => {
+                write!(f, "failed to read {path}: {source}")
+            }
+            MarkdownError::WriteError { path, source } => {
+                write!(f, "failed to write {path}: {source}")
+            }
+            MarkdownError::IoError(source) => write!(f, "I/O error: {source}"),
+        }
+    }
+}
+
+impl Error for MarkdownError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            MarkdownError::ReadError { source, .. }
+            | MarkdownError::WriteError { source, .. }
+            | MarkdownError::IoError(source) => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for MarkdownError {
+    fn from(err: io::Error) -> Self {
+        MarkdownError::IoError(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timed_out_read_error_is_recoverable() {
+        let err = MarkdownError::ReadError {
+            path: "notes.md".to_string(),
+            source: io::Error::new(io::ErrorKind::TimedOut, "timed out"),
+        };
+        assert!(err.is_recoverable());
+    }
+
+    #[test]
+    fn interrupted_io_error_is_recoverable() {
+        let err = MarkdownError::IoError(io::Error::new(io::ErrorKind::Interrupted, "eintr"));
+        assert!(err.is_recoverable());
+    }
+
+    #[test]
+    fn file_not_found_is_not_recoverable() {
+        let err = MarkdownError::FileNotFound("missing.md".to_string());
+        assert!(!err.is_recoverable());
+    }
+
+    #[test]
+    fn permission_denied_read_error_is_not_recoverable() {
+        let err = MarkdownError::ReadError {
+            path: "locked.md".to_string(),
+            source: io::Error::new(io::ErrorKind::PermissionDenied, "denied"),
+        };
+        assert!(!err.is_recoverable());
+    }
+
+    #[test]
+    fn structural_errors_are_not_recoverable() {
+        assert!(!MarkdownError::InvalidExtension("a.txt".to_string()).is_recoverable());
+        assert!(!MarkdownError::NotAFile("dir".to_string()).is_recoverable());
+        assert!(!MarkdownError::FileTooLarge {
+            path: "big.md".to_string(),
+            size: 20,
+            limit: 10,
+        }
+        .is_recoverable());
+    }
+}
@@ -0,0 +1,246 @@
+//! Link extraction and local link validation.
+
+use std::path::{Path, PathBuf};
+
+use crate::markdown::code::{FenceEvent, FenceTracker};
+
+/// An inline `[text](target)` link found in a markdown document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Link {
+    /// The link text between the brackets.
+    pub text: String,
+    /// The raw target between the parentheses.
+    pub target: String,
+    /// 1-based line number the link appears on.
+    pub line: usize,
+}
+
+/// A local link whose resolved target does not exist on disk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BrokenLink {
+    /// The offending link.
+    pub link: Link,
+    /// The path the target resolved to.
+    pub resolved: PathBuf,
+}
+
+/// Extracts every inline link outside code fences, in document order.
+pub fn extract_links(content: &str) -> Vec<Link> {
+    let mut links = Vec::new();
+    let mut tracker = FenceTracker::new();
+
+    for (index, line) in content.lines().enumerate() {
+        if tracker.observe(line) != FenceEvent::Outside {
+            continue;
+        }
+        scan_line(line, index + 1, &mut links);
+    }
+    links
+}
+
+/// Collects the `[text](target)` occurrences on a single line.
+fn scan_line(line: &str, line_number: usize, links: &mut Vec<Link>) {
+    let chars: Vec<char> = line.chars().collect();
+    let mut i = 0;
+
+    while i < chars.len() {
+        if chars[i] == '[' {
+            if let Some((text_end, url_end)) = find_link(&chars, i) {
+                links.push(Link {
+                    text: chars[i + 1..text_end].iter().collect(),
+                    target: chars[text_end + 2..url_end].iter().collect(),
+                    line: line_number,
+                });
+                i = url_end + 1;
+                continue;
+            }
+        }
+        i += 1;
+    }
+}
+
+/// Matches `[text](target)` starting at the `[` at `start`, returning the
+/// indices of the closing `]` and closing `)`.
+fn find_link(chars: &[char], start: usize) -> Option<(usize, usize)> {
+    let text_end = (start + 1..chars.len()).find(|&j| chars[j] == ']')?;
+    if chars.get(text_end + 1) != Some(&'(') {
+        return None;
+    }
+    let url_end = (text_end + 2..chars.len()).find(|&j| chars[j] == ')')?;
+    Some((text_end, url_end))
+}
+
+/// Returns `true` for targets that local checking should skip: external
+/// URLs, mail links, and pure in-page anchors.
+fn is_external_or_anchor(target: &str) -> bool {
+    target.starts_with("http://")
+        || target.starts_with("https://")
+        || target.starts_with("mailto:")
+        || target.starts_with('#')
+}
+
+/// Checks which local links are broken, resolving relative targets
+/// against `base_dir` (the directory of the file being checked). Any
+/// `#fragment` suffix is ignored when resolving.
+pub fn check_local_links(base_dir: &Path, links: &[Link]) -> Vec<BrokenLink> {
+    let mut broken = Vec::new();
+    for link in links {
+        if is_external_or_anchor(&link.target) {
+            continue;
+        }
+        let path_part = link.target.split('#').next().unwrap_or("");
+        if path_part.is_empty() {
+            continue;
+        }
+        let resolved = base_dir.join(path_part);
+        if !resolved.exists() {
+            broken.push(BrokenLink {
+                link: link.clone(),
+                resolved,
+            });
+        }
+    }
+    broken
+}
+
+/// Outcome of probing an external link.
+#[cfg(feature = "external-links")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExternalStatus {
+    /// The server answered with this HTTP status code.
+    Status(u16),
+    /// The request failed before a status was received (DNS, connect,
+    /// timeout, malformed response, or an unsupported scheme).
+    Error(String),
+}
+
+#[cfg(feature = "external-links")]
+impl ExternalStatus {
+    /// 2xx and 3xx responses count as a working link.
+    pub fn is_ok(&self) -> bool {
+        matches!(self, ExternalStatus::Status(code) if (200..400).contains(code))
+    }
+}
+
+/// Probes every `http(s)://` link in `content` with a HEAD request.
+///
+/// Uses a minimal built-in blocking client (plain TCP, no TLS), so
+/// `https://` targets report an [`ExternalStatus::Error`] rather than
+/// being probed. The timeout applies to connect, send, and receive
+/// individually.
+#[cfg(feature = "external-links")]
+pub fn check_external_links(
+    content: &str,
+    timeout: std::time::Duration,
+) -> Vec<(Link, ExternalStatus)> {
+    extract_links(content)
+        .into_iter()
+        .filter(|link| {
+            link.target.starts_with("http://") || link.target.starts_with("https://")
+        })
+        .map(|link| {
+            let status = probe_url(&link.target, timeout);
+            (link, status)
+        })
+        .collect()
+}
+
+/// Issues a blocking HEAD request and parses the status line.
+#[cfg(feature = "external-links")]
+fn probe_url(url: &str, timeout: std::time::Duration) -> ExternalStatus {
+    use std::io::{Read, Write};
+    use std::net::{TcpStream, ToSocketAddrs};
+
+    let Some(rest) = url.strip_prefix("http://") else {
+        return ExternalStatus::Error("unsupported scheme (only http:// can be probed)".into());
+    };
+    let (authority, path) = match rest.find('/') {
+        Some(slash) => (&rest[..slash], &rest[slash..]),
+        None => (rest, "/"),
+    };
+    let host = authority.split(':').next().unwrap_or(authority);
+    let address = if authority.contains(':') {
+        authority.to_string()
+    } else {
+        format!("{authority}:80")
+    };
+
+    let result = (|| -> std::io::Result<ExternalStatus> {
+        let addr = address
+            .to_socket_addrs()?
+            .next()
+            .ok_or_else(|| std::io::Error::other("no address resolved"))?;
+        let mut stream = TcpStream::connect_timeout(&addr, timeout)?;
+        stream.set_read_timeout(Some(timeout))?;
+        stream.set_write_timeout(Some(timeout))?;
+        write!(
+            stream,
+            "HEAD {path} HTTP/1.1\r\nHost: {host}\r\nConnection: close\r\n\r\n"
+        )?;
+        let mut response = String::new();
+        stream.take(1024).read_to_string(&mut response).ok();
+        let status = response
+            .split_whitespace()
+            .nth(1)
+            .and_then(|code| code.parse::<u16>().ok())
+            .ok_or_else(|| std::io::Error::other("malformed status line"))?;
+        Ok(ExternalStatus::Status(status))
+    })();
+
+    match result {
+        Ok(status) => status,
+        Err(err) => ExternalStatus::Error(err.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn extracts_links_with_line_numbers() {
+        let content = "See [one](a.md).\n\nAnd [two](../b.md) or [three](#anchor).\n";
+        let links = extract_links(content);
+        assert_eq!(links.len(), 3);
+        assert_eq!(links[0].text, "one");
+        assert_eq!(links[0].target, "a.md");
+        assert_eq!(links[0].line, 1);
+        assert_eq!(links[1].target, "../b.md");
+        assert_eq!(links[2].line, 3);
+    }
+
+    #[test]
+    fn links_inside_fences_are_ignored() {
+        let content = "```\n[not a link](x.md)\n```\n[real](y.md)\n";
+        let links = extract_links(content);
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].target, "y.md");
+    }
+
+    #[test]
+    fn external_and_anchor_targets_are_skipped() {
+        let links = extract_links(
+            "[a](https://example.com) [b](http://x.y) [c](mailto:a@b.c) [d](#frag)\n",
+        );
+        let broken = check_local_links(Path::new("/nonexistent"), &links);
+        assert!(broken.is_empty());
+    }
+
+    #[test]
+    fn missing_local_targets_are_reported() {
+        let dir = std::env::temp_dir();
+        let existing = dir.join(format!("ai_coding_agent_link_{}.md", std::process::id()));
+        fs::write(&existing, "x").unwrap();
+        let file_name = existing.file_name().unwrap().to_str().unwrap().to_string();
+
+        let content = format!("[ok]({file_name}) [bad](definitely-missing.md#frag)\n");
+        let links = extract_links(&content);
+        let broken = check_local_links(&dir, &links);
+        assert_eq!(broken.len(), 1);
+        assert_eq!(broken[0].link.text, "bad");
+        assert_eq!(broken[0].resolved, dir.join("definitely-missing.md"));
+
+        fs::remove_file(existing).ok();
+    }
+}
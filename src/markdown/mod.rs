@@ -0,0 +1,11 @@
+//! Markdown handling: file validation, reading, and errors.
+
+pub mod code;
+pub mod error;
+pub mod links;
+pub mod reader;
+pub mod render;
+pub mod stats;
+pub mod tables;
+pub mod toc;
+pub mod transform;
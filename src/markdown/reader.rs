@@ -0,0 +1,207 @@
+//! Validated reading of markdown files from disk.
+
+use std::fs;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+
+use crate::markdown::error::{MarkdownError, MarkdownResult};
+
+/// Maximum file size the reader will accept, in bytes (10 MiB).
+pub const MAX_FILE_SIZE: u64 = 10 * 1024 * 1024;
+
+/// Returns `true` if the path carries a markdown extension.
+pub fn is_markdown_file(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(|ext| ext.to_str()),
+        Some("md") | Some("markdown")
+    )
+}
+
+/// Reads a markdown file into a `String` after validating the path.
+///
+/// Validation order: the path must exist, be a regular file, carry a
+/// markdown extension, and fit within [`MAX_FILE_SIZE`]. Only then is the
+/// file actually read and decoded as UTF-8.
+///
+/// Accepts anything path-like (`&str`, `&Path`, `PathBuf`, ...), so
+/// callers holding paths from `read_dir` or similar don't need a lossy
+/// `to_str()` round trip.
+pub fn read_markdown_file<P: AsRef<Path>>(path: P) -> MarkdownResult<String> {
+    let path_ref = path.as_ref();
+    validate_markdown_path(path_ref)?;
+
+    let bytes = fs::read(path_ref).map_err(|source| MarkdownError::ReadError {
+        path: path_ref.display().to_string(),
+        source,
+    })?;
+    String::from_utf8(bytes)
+        .map_err(|_| MarkdownError::InvalidUtf8(path_ref.display().to_string()))
+}
+
+/// Reads a markdown file once, returning both raw bytes and decoded text.
+///
+/// Runs the same validation (including the [`MAX_FILE_SIZE`] limit on the
+/// byte length) as [`read_markdown_file`], then decodes a copy of the
+/// bytes as UTF-8. Useful when a caller needs the bytes for checksumming
+/// alongside the text, without reading the file twice.
+pub fn read_markdown_raw<P: AsRef<Path>>(path: P) -> MarkdownResult<(Vec<u8>, String)> {
+    let path_ref = path.as_ref();
+    validate_markdown_path(path_ref)?;
+
+    let bytes = fs::read(path_ref).map_err(|source| MarkdownError::ReadError {
+        path: path_ref.display().to_string(),
+        source,
+    })?;
+    let text = String::from_utf8(bytes.clone())
+        .map_err(|_| MarkdownError::InvalidUtf8(path_ref.display().to_string()))?;
+    Ok((bytes, text))
+}
+
+/// Opens a markdown file for line-by-line streaming.
+///
+/// Runs the same validation as [`read_markdown_file`] up front, so an
+/// invalid path fails before any iterator is produced, then yields lines
+/// from a `BufReader` without loading the whole file. Per-line I/O
+/// errors surface through the iterator items.
+pub fn read_markdown_lines<P: AsRef<Path>>(
+    path: P,
+) -> MarkdownResult<impl Iterator<Item = io::Result<String>>> {
+    let path_ref = path.as_ref();
+    validate_markdown_path(path_ref)?;
+
+    let file = fs::File::open(path_ref).map_err(|source| MarkdownError::ReadError {
+        path: path_ref.display().to_string(),
+        source,
+    })?;
+    Ok(BufReader::new(file).lines())
+}
+
+/// Runs the existence/type/extension/size checks shared by the readers.
+fn validate_markdown_path(path: &Path) -> MarkdownResult<()> {
+    let display = path.display().to_string();
+    if !path.exists() {
+        return Err(MarkdownError::FileNotFound(display));
+    }
+    let metadata = fs::metadata(path).map_err(|source| MarkdownError::ReadError {
+        path: display.clone(),
+        source,
+    })?;
+    if !metadata.is_file() {
+        return Err(MarkdownError::NotAFile(display));
+    }
+    if !is_markdown_file(path) {
+        return Err(MarkdownError::InvalidExtension(display));
+    }
+    if metadata.len() > MAX_FILE_SIZE {
+        return Err(MarkdownError::FileTooLarge {
+            path: display,
+            size: metadata.len(),
+            limit: MAX_FILE_SIZE,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::PathBuf;
+
+    /// Creates a uniquely named file under the system temp dir.
+    pub(crate) fn temp_file(name: &str, contents: &str) -> PathBuf {
+        let mut path = std::env::temp_dir();
+        path.push(format!("ai_coding_agent_test_{}_{name}", std::process::id()));
+        fs::write(&path, contents).expect("failed to write temp file");
+        path
+    }
+
+    #[test]
+    fn reads_a_valid_markdown_file() {
+        let path = temp_file("valid.md", "# Hello\n\nSome text.\n");
+        let content = read_markdown_file(path.to_str().unwrap()).unwrap();
+        assert_eq!(content, "# Hello\n\nSome text.\n");
+        fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn missing_file_is_file_not_found() {
+        let err = read_markdown_file("/no/such/place/missing.md").unwrap_err();
+        assert!(matches!(err, MarkdownError::FileNotFound(_)));
+    }
+
+    #[test]
+    fn wrong_extension_is_rejected() {
+        let path = temp_file("notes.txt", "plain text");
+        let err = read_markdown_file(path.to_str().unwrap()).unwrap_err();
+        assert!(matches!(err, MarkdownError::InvalidExtension(_)));
+        fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn directory_is_not_a_file() {
+        let dir = std::env::temp_dir();
+        let err = read_markdown_file(dir.to_str().unwrap()).unwrap_err();
+        assert!(matches!(err, MarkdownError::NotAFile(_)));
+    }
+
+    #[test]
+    fn accepts_a_pathbuf_directly() {
+        let path: PathBuf = temp_file("pathbuf.md", "# From a PathBuf\n");
+        let content = read_markdown_file(path.clone()).unwrap();
+        assert_eq!(content, "# From a PathBuf\n");
+        let content = read_markdown_file(path.as_path()).unwrap();
+        assert_eq!(content, "# From a PathBuf\n");
+        fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn raw_read_returns_matching_bytes_and_text() {
+        let path = temp_file("raw.md", "# Raw 🚀\n");
+        let (bytes, text) = read_markdown_raw(&path).unwrap();
+        assert_eq!(bytes.len() as u64, fs::metadata(&path).unwrap().len());
+        assert_eq!(text, read_markdown_file(&path).unwrap());
+        assert_eq!(bytes, text.as_bytes());
+        fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn raw_read_rejects_invalid_utf8() {
+        let mut path = std::env::temp_dir();
+        path.push(format!("ai_coding_agent_test_{}_bad.md", std::process::id()));
+        fs::write(&path, [0xff, 0xfe, 0x00]).unwrap();
+        let err = read_markdown_raw(&path).unwrap_err();
+        assert!(matches!(err, MarkdownError::InvalidUtf8(_)));
+        fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn streams_lines_from_a_valid_file() {
+        let path = temp_file("streamed.md", "# One\nTwo\nThree\n");
+        let lines: Vec<String> = read_markdown_lines(path.to_str().unwrap())
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(lines, vec!["# One", "Two", "Three"]);
+        fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn streaming_validation_fails_before_iteration() {
+        let path = temp_file("streamed.txt", "wrong extension");
+        let err = match read_markdown_lines(path.to_str().unwrap()) {
+            Ok(_) => panic!("expected validation to fail"),
+            Err(err) => err,
+        };
+        assert!(matches!(err, MarkdownError::InvalidExtension(_)));
+        fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn is_markdown_file_accepts_both_extensions() {
+        assert!(is_markdown_file(Path::new("a.md")));
+        assert!(is_markdown_file(Path::new("b.markdown")));
+        assert!(!is_markdown_file(Path::new("c.txt")));
+        assert!(!is_markdown_file(Path::new("noext")));
+    }
+}
@@ -0,0 +1,469 @@
+//! Rendering of markdown content to HTML.
+//!
+//! This is deliberately not a full CommonMark implementation: it covers
+//! the subset the tool needs — ATX headings, paragraphs, emphasis,
+//! inline code, fenced code blocks, lists, and links — while staying
+//! fence-aware so `*` and `#` inside code blocks pass through verbatim.
+
+use crate::markdown::code::{FenceEvent, FenceTracker};
+use crate::markdown::tables::{parse_tables, Alignment, Table};
+use crate::markdown::toc::parse_heading_line;
+
+/// An open list on the rendering stack.
+struct OpenList {
+    indent: usize,
+    ordered: bool,
+    item_open: bool,
+}
+
+/// Renders markdown `content` to an HTML fragment.
+pub fn to_html(content: &str) -> String {
+    let mut out = String::new();
+    let mut tracker = FenceTracker::new();
+    let mut paragraph: Vec<String> = Vec::new();
+    let mut lists: Vec<OpenList> = Vec::new();
+    let tables = parse_tables(content);
+    let mut next_table = 0;
+    let mut skip_until = 0;
+
+    for (index, line) in content.lines().enumerate() {
+        let line_number = index + 1;
+        // The tracker must see every line to keep fence state straight,
+        // even the ones a table already consumed.
+        let event = tracker.observe(line);
+        if line_number <= skip_until {
+            continue;
+        }
+
+        if next_table < tables.len() && tables[next_table].start_line == line_number {
+            flush_paragraph(&mut out, &mut paragraph);
+            close_lists(&mut out, &mut lists, 0);
+            out.push_str(&render_table(&tables[next_table]));
+            skip_until = tables[next_table].end_line;
+            next_table += 1;
+            continue;
+        }
+
+        match event {
+            FenceEvent::Opened(language) => {
+                flush_paragraph(&mut out, &mut paragraph);
+                close_lists(&mut out, &mut lists, 0);
+                match language {
+                    Some(lang) => {
+                        out.push_str(&format!(
+                            "<pre><code class=\"language-{}\">\n",
+                            escape_html(&lang)
+                        ));
+                    }
+                    None => out.push_str("<pre><code>\n"),
+                }
+            }
+            FenceEvent::Inside => {
+                out.push_str(&escape_html(line));
+                out.push('\n');
+            }
+            FenceEvent::Closed => out.push_str("</code></pre>\n"),
+            FenceEvent::Outside => {
+                render_block_line(&mut out, line, &mut paragraph, &mut lists);
+            }
+        }
+    }
+
+    flush_paragraph(&mut out, &mut paragraph);
+    close_lists(&mut out, &mut lists, 0);
+    if tracker.in_fence() {
+        out.push_str("</code></pre>\n");
+    }
+    out
+}
+
+/// Handles one ordinary (non-fence) markdown line.
+fn render_block_line(
+    out: &mut String,
+    line: &str,
+    paragraph: &mut Vec<String>,
+    lists: &mut Vec<OpenList>,
+) {
+    let trimmed = line.trim_start();
+    let indent = line.len() - trimmed.len();
+
+    if trimmed.is_empty() {
+        flush_paragraph(out, paragraph);
+        return;
+    }
+
+    if let Some(heading) = parse_heading_line(trimmed) {
+        flush_paragraph(out, paragraph);
+        close_lists(out, lists, 0);
+        let (level, text) = heading;
+        out.push_str(&format!("<h{level}>{}</h{level}>\n", render_inline(text)));
+        return;
+    }
+
+    if let Some((ordered, text)) = parse_list_item(trimmed) {
+        flush_paragraph(out, paragraph);
+        push_list_item(out, lists, indent, ordered, text);
+        return;
+    }
+
+    close_lists(out, lists, 0);
+    paragraph.push(trimmed.to_string());
+}
+
+/// Renders a parsed table, applying `text-align` styles from the
+/// alignment spec to both header and body cells.
+fn render_table(table: &Table) -> String {
+    let mut out = String::from("<table>\n<thead>\n<tr>");
+    for (cell, alignment) in table.headers.iter().zip(&table.alignments) {
+        out.push_str(&table_cell("th", cell, *alignment));
+    }
+    out.push_str("</tr>\n</thead>\n<tbody>\n");
+    for row in &table.rows {
+        out.push_str("<tr>");
+        for (cell, alignment) in row.iter().zip(&table.alignments) {
+            out.push_str(&table_cell("td", cell, *alignment));
+        }
+        out.push_str("</tr>\n");
+    }
+    out.push_str("</tbody>\n</table>\n");
+    out
+}
+
+fn table_cell(tag: &str, text: &str, alignment: Alignment) -> String {
+    let content = render_inline(text);
+    match alignment.css() {
+        Some(css) => format!("<{tag} style=\"text-align:{css}\">{content}</{tag}>"),
+        None => format!("<{tag}>{content}</{tag}>"),
+    }
+}
+
+/// Recognizes a list item marker, returning (ordered, item text).
+fn parse_list_item(trimmed: &str) -> Option<(bool, &str)> {
+    for marker in ["- ", "* ", "+ "] {
+        if let Some(rest) = trimmed.strip_prefix(marker) {
+            return Some((false, rest));
+        }
+    }
+    let digits = trimmed.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits > 0 {
+        if let Some(rest) = trimmed[digits..].strip_prefix(". ") {
+            return Some((true, rest));
+        }
+    }
+    None
+}
+
+/// Emits a list item, opening or closing lists to match its indentation.
+fn push_list_item(
+    out: &mut String,
+    lists: &mut Vec<OpenList>,
+    indent: usize,
+    ordered: bool,
+    text: &str,
+) {
+    while lists
+        .last()
+        .is_some_and(|top| top.indent > indent || (top.indent == indent && top.ordered != ordered))
+    {
+        close_one_list(out, lists);
+    }
+
+    let deeper = lists.last().is_none_or(|top| top.indent < indent);
+    if deeper {
+        // A deeper list nests inside the still-open parent <li>.
+        if lists.last().is_some() {
+            out.push('\n');
+        }
+        out.push_str(if ordered { "<ol>\n" } else { "<ul>\n" });
+        lists.push(OpenList {
+            indent,
+            ordered,
+            item_open: false,
+        });
+    }
+
+    let top = lists.last_mut().expect("a list is open");
+    if top.item_open {
+        out.push_str("</li>\n");
+    }
+    out.push_str(&format!("<li>{}", render_inline(text)));
+    top.item_open = true;
+}
+
+/// Closes lists until the stack is at most `depth` entries deep.
+fn close_lists(out: &mut String, lists: &mut Vec<OpenList>, depth: usize) {
+    while lists.len() > depth {
+        close_one_list(out, lists);
+    }
+}
+
+fn close_one_list(out: &mut String, lists: &mut Vec<OpenList>) {
+    let list = lists.pop().expect("a list is open");
+    if list.item_open {
+        out.push_str("</li>\n");
+    }
+    out.push_str(if list.ordered { "</ol>\n" } else { "</ul>\n" });
+}
+
+/// Flushes any buffered paragraph lines as a single `<p>`.
+fn flush_paragraph(out: &mut String, paragraph: &mut Vec<String>) {
+    if paragraph.is_empty() {
+        return;
+    }
+    let text = paragraph.join(" ");
+    paragraph.clear();
+    out.push_str(&format!("<p>{}</p>\n", render_inline(&text)));
+}
+
+/// Strips markdown formatting, leaving readable plain text.
+///
+/// Headings lose their `#` markers, inline emphasis/code/link syntax is
+/// reduced to its text, and fence lines are dropped while fenced code
+/// itself is kept verbatim.
+pub fn to_plain(content: &str) -> String {
+    let mut out = String::new();
+    let mut tracker = FenceTracker::new();
+
+    for line in content.lines() {
+        match tracker.observe(line) {
+            FenceEvent::Opened(_) | FenceEvent::Closed => {}
+            FenceEvent::Inside => {
+                out.push_str(line);
+                out.push('\n');
+            }
+            FenceEvent::Outside => {
+                let trimmed = line.trim_start();
+                let text = match parse_heading_line(trimmed) {
+                    Some((_, text)) => text,
+                    None => trimmed,
+                };
+                out.push_str(&strip_inline(text));
+                out.push('\n');
+            }
+        }
+    }
+    out
+}
+
+/// Reduces inline markup to its plain text.
+fn strip_inline(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        if chars[i] == '`' {
+            if let Some(end) = find_char(&chars, i + 1, '`') {
+                out.extend(&chars[i + 1..end]);
+                i = end + 1;
+                continue;
+            }
+        }
+        if chars[i] == '[' {
+            if let Some((text_end, url_end)) = find_link(&chars, i) {
+                let link_text: String = chars[i + 1..text_end].iter().collect();
+                out.push_str(&strip_inline(&link_text));
+                i = url_end + 1;
+                continue;
+            }
+        }
+        if chars[i] == '*' {
+            i += 1;
+            continue;
+        }
+        out.push(chars[i]);
+        i += 1;
+    }
+    out
+}
+
+/// Renders inline markdown: code spans, links, bold, and italic.
+pub(crate) fn render_inline(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        // Code spans bind tightest and suppress other inline markup.
+        if chars[i] == '`' {
+            if let Some(end) = find_char(&chars, i + 1, '`') {
+                let code: String = chars[i + 1..end].iter().collect();
+                out.push_str(&format!("<code>{}</code>", escape_html(&code)));
+                i = end + 1;
+                continue;
+            }
+        }
+        if chars[i] == '[' {
+            if let Some((text_end, url_end)) = find_link(&chars, i) {
+                let link_text: String = chars[i + 1..text_end].iter().collect();
+                let url: String = chars[text_end + 2..url_end].iter().collect();
+                out.push_str(&format!(
+                    "<a href=\"{}\">{}</a>",
+                    escape_html(&url),
+                    render_inline(&link_text)
+                ));
+                i = url_end + 1;
+                continue;
+            }
+        }
+        if chars[i] == '*' && i + 1 < chars.len() && chars[i + 1] == '*' {
+            if let Some(end) = find_pair(&chars, i + 2) {
+                let inner: String = chars[i + 2..end].iter().collect();
+                out.push_str(&format!("<strong>{}</strong>", render_inline(&inner)));
+                i = end + 2;
+                continue;
+            }
+        }
+        if chars[i] == '*' {
+            if let Some(end) = find_char(&chars, i + 1, '*') {
+                let inner: String = chars[i + 1..end].iter().collect();
+                out.push_str(&format!("<em>{}</em>", render_inline(&inner)));
+                i = end + 1;
+                continue;
+            }
+        }
+        out.push_str(&escape_char(chars[i]));
+        i += 1;
+    }
+    out
+}
+
+/// Finds the next occurrence of `wanted` at or after `from`.
+fn find_char(chars: &[char], from: usize, wanted: char) -> Option<usize> {
+    (from..chars.len()).find(|&j| chars[j] == wanted)
+}
+
+/// Finds the next `**` at or after `from`, returning its start index.
+fn find_pair(chars: &[char], from: usize) -> Option<usize> {
+    (from..chars.len().saturating_sub(1)).find(|&j| chars[j] == '*' && chars[j + 1] == '*')
+}
+
+/// Matches `[text](url)` starting at the `[` at index `start`, returning
+/// the indices of the closing `]` and closing `)`.
+fn find_link(chars: &[char], start: usize) -> Option<(usize, usize)> {
+    let text_end = find_char(chars, start + 1, ']')?;
+    if chars.get(text_end + 1) != Some(&'(') {
+        return None;
+    }
+    let url_end = find_char(chars, text_end + 2, ')')?;
+    Some((text_end, url_end))
+}
+
+/// Escapes `<`, `>`, and `&` for HTML text content.
+pub(crate) fn escape_html(text: &str) -> String {
+    text.chars().map(|c| escape_char(c).to_string()).collect()
+}
+
+fn escape_char(c: char) -> String {
+    match c {
+        '&' => "&amp;".to_string(),
+        '<' => "&lt;".to_string(),
+        '>' => "&gt;".to_string(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_headings_and_paragraphs() {
+        let html = to_html("# Title\n\nHello world.\n");
+        assert_eq!(html, "<h1>Title</h1>\n<p>Hello world.</p>\n");
+    }
+
+    #[test]
+    fn renders_inline_markup() {
+        let html = to_html("Some **bold** and *italic* and `code`.\n");
+        assert_eq!(
+            html,
+            "<p>Some <strong>bold</strong> and <em>italic</em> and <code>code</code>.</p>\n"
+        );
+    }
+
+    #[test]
+    fn renders_links() {
+        let html = to_html("See [the docs](https://example.com/a?b=1&c=2).\n");
+        assert_eq!(
+            html,
+            "<p>See <a href=\"https://example.com/a?b=1&amp;c=2\">the docs</a>.</p>\n"
+        );
+    }
+
+    #[test]
+    fn escapes_text_content() {
+        let html = to_html("1 < 2 & 3 > 2\n");
+        assert_eq!(html, "<p>1 &lt; 2 &amp; 3 &gt; 2</p>\n");
+    }
+
+    #[test]
+    fn code_fence_content_is_not_mangled() {
+        let html = to_html("```rust\nlet x = a * b; // # not a heading\n```\n");
+        assert_eq!(
+            html,
+            "<pre><code class=\"language-rust\">\nlet x = a * b; // # not a heading\n</code></pre>\n"
+        );
+    }
+
+    #[test]
+    fn renders_unordered_and_ordered_lists() {
+        let html = to_html("- one\n- two\n\n1. first\n2. second\n");
+        assert_eq!(
+            html,
+            "<ul>\n<li>one</li>\n<li>two</li>\n</ul>\n<ol>\n<li>first</li>\n<li>second</li>\n</ol>\n"
+        );
+    }
+
+    #[test]
+    fn renders_nested_lists_inside_parent_item() {
+        let html = to_html("- outer\n  - inner\n- next\n");
+        assert_eq!(
+            html,
+            "<ul>\n<li>outer\n<ul>\n<li>inner</li>\n</ul>\n</li>\n<li>next</li>\n</ul>\n"
+        );
+    }
+
+    #[test]
+    fn unterminated_fence_is_closed_at_eof() {
+        let html = to_html("```\ncode\n");
+        assert_eq!(html, "<pre><code>\ncode\n</code></pre>\n");
+    }
+
+    #[test]
+    fn renders_a_two_column_table() {
+        let html = to_html("| Name | Count |\n| --- | --- |\n| *a* | 1 |\n| b | 2 & 3 |\n");
+        assert_eq!(
+            html,
+            "<table>\n<thead>\n<tr><th>Name</th><th>Count</th></tr>\n</thead>\n<tbody>\n\
+             <tr><td><em>a</em></td><td>1</td></tr>\n\
+             <tr><td>b</td><td>2 &amp; 3</td></tr>\n\
+             </tbody>\n</table>\n"
+        );
+    }
+
+    #[test]
+    fn center_alignment_becomes_a_style_attribute() {
+        let html = to_html("| A | B |\n| :-: | ---: |\n| x | y |\n");
+        assert!(html.contains("<th style=\"text-align:center\">A</th>"));
+        assert!(html.contains("<td style=\"text-align:right\">y</td>"));
+    }
+
+    #[test]
+    fn text_after_a_table_still_renders() {
+        let html = to_html("| A |\n| --- |\n| x |\n\nAfter.\n");
+        assert!(html.ends_with("</table>\n<p>After.</p>\n"));
+    }
+
+    #[test]
+    fn to_plain_strips_markup_but_keeps_code() {
+        let plain = to_plain("# Title\n\nSome **bold** [link](https://x.y).\n\n```\nlet a = 1;\n```\n");
+        assert_eq!(plain, "Title\n\nSome bold link.\n\nlet a = 1;\n");
+    }
+
+    #[test]
+    fn unmatched_inline_markers_stay_literal() {
+        let html = to_html("a * b and [text] alone\n");
+        assert_eq!(html, "<p>a * b and [text] alone</p>\n");
+    }
+}
@@ -0,0 +1,268 @@
+//! Document metrics for content audits.
+
+use crate::markdown::code::{FenceEvent, FenceTracker};
+
+/// Aggregate counts for a markdown document.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DocStats {
+    /// Number of lines.
+    pub lines: usize,
+    /// Number of whitespace-delimited tokens.
+    pub words: usize,
+    /// Number of Unicode scalar values (not bytes).
+    pub chars: usize,
+    /// Number of ATX headings outside code fences.
+    pub headings: usize,
+    /// Number of fenced code blocks.
+    pub code_blocks: usize,
+}
+
+/// Computes [`DocStats`] for `content`.
+///
+/// Heading and code-block counting go through [`FenceTracker`] so that
+/// `#` lines inside code fences are not miscounted as headings.
+pub fn compute_stats(content: &str) -> DocStats {
+    let mut stats = DocStats {
+        lines: content.lines().count(),
+        words: content.split_whitespace().count(),
+        chars: content.chars().count(),
+        ..DocStats::default()
+    };
+
+    let mut tracker = FenceTracker::new();
+    for line in content.lines() {
+        match tracker.observe(line) {
+            FenceEvent::Opened(_) => stats.code_blocks += 1,
+            FenceEvent::Outside => {
+                if is_heading(line) {
+                    stats.headings += 1;
+                }
+            }
+            FenceEvent::Inside | FenceEvent::Closed => {}
+        }
+    }
+    stats
+}
+
+/// Counts the words that "count" for document budgets: whitespace
+/// tokens outside YAML frontmatter and outside fenced code blocks.
+/// This is the measure behind `--min-words` and `--max-words`, so a
+/// placeholder file holding only a frontmatter block and a code sample
+/// scores zero.
+pub fn count_words(content: &str) -> usize {
+    let mut words = 0;
+    let mut tracker = FenceTracker::new();
+    let mut in_frontmatter = false;
+    let mut first_content_line = true;
+
+    for line in content.lines() {
+        if first_content_line {
+            first_content_line = false;
+            if line.trim_end() == "---" {
+                in_frontmatter = true;
+                continue;
+            }
+        } else if in_frontmatter {
+            if line.trim_end() == "---" {
+                in_frontmatter = false;
+            }
+            continue;
+        }
+
+        if tracker.observe(line) == FenceEvent::Outside {
+            words += line.split_whitespace().count();
+        }
+    }
+    words
+}
+
+/// Word count for one heading-delimited section.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SectionWords {
+    /// The heading text, or `(preamble)` for content before any heading.
+    pub section: String,
+    /// The heading level; the synthetic preamble section is level 0.
+    pub level: usize,
+    /// Whitespace-delimited tokens in the section body (the heading
+    /// line itself is not counted).
+    pub words: usize,
+}
+
+/// Splits `content` at its headings and counts the words in each
+/// section's body. Content before the first heading is reported as a
+/// synthetic `(preamble)` section with level 0; an empty preamble is
+/// omitted.
+pub fn words_per_section(content: &str) -> Vec<SectionWords> {
+    let mut sections = vec![SectionWords {
+        section: "(preamble)".to_string(),
+        level: 0,
+        words: 0,
+    }];
+    let mut tracker = FenceTracker::new();
+
+    for line in content.lines() {
+        if tracker.observe(line) == FenceEvent::Outside {
+            if let Some((level, text)) = crate::markdown::toc::parse_heading_line(line) {
+                sections.push(SectionWords {
+                    section: text.to_string(),
+                    level,
+                    words: 0,
+                });
+                continue;
+            }
+        }
+        let current = sections.last_mut().expect("preamble section exists");
+        current.words += line.split_whitespace().count();
+    }
+
+    // Drop the synthetic preamble when there was nothing before the
+    // first heading.
+    if sections.len() > 1 && sections[0].words == 0 {
+        sections.remove(0);
+    }
+    sections
+}
+
+/// Signed differences between two [`DocStats`], new minus old.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StatsDiff {
+    pub words: i64,
+    pub chars: i64,
+    pub lines: i64,
+}
+
+/// Computes the delta from `old` to `new`.
+pub fn diff_stats(old: &DocStats, new: &DocStats) -> StatsDiff {
+    StatsDiff {
+        words: new.words as i64 - old.words as i64,
+        chars: new.chars as i64 - old.chars as i64,
+        lines: new.lines as i64 - old.lines as i64,
+    }
+}
+
+impl std::fmt::Display for StatsDiff {
+    /// Formats like `+142 words, +890 chars, -3 lines`; zeros keep the
+    /// explicit `+0` so "no change" is still visible.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:+} words, {:+} chars, {:+} lines",
+            self.words, self.chars, self.lines
+        )
+    }
+}
+
+/// An ATX heading: one to six `#` followed by a space or end of line.
+pub(crate) fn is_heading(line: &str) -> bool {
+    crate::markdown::toc::parse_heading_line(line).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_lines_words_and_headings() {
+        let content = "# Title\n\nOne two three.\n\n## Section\n\nFour five.\n";
+        let stats = compute_stats(content);
+        assert_eq!(stats.lines, 7);
+        assert_eq!(stats.words, 9);
+        assert_eq!(stats.headings, 2);
+        assert_eq!(stats.code_blocks, 0);
+    }
+
+    #[test]
+    fn hash_lines_inside_fences_are_not_headings() {
+        let content = "# Real\n\n```sh\n# just a comment\n```\n";
+        let stats = compute_stats(content);
+        assert_eq!(stats.headings, 1);
+        assert_eq!(stats.code_blocks, 1);
+    }
+
+    #[test]
+    fn chars_count_scalar_values_not_bytes() {
+        let content = "héllo 🚀\n";
+        let stats = compute_stats(content);
+        assert_eq!(stats.chars, 8);
+        assert!(content.len() > stats.chars);
+        assert_eq!(stats.words, 2);
+    }
+
+    #[test]
+    fn emoji_fixture_chars_are_not_byte_counts() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/emoji.md");
+        let content = std::fs::read_to_string(path).unwrap();
+        let stats = compute_stats(content.as_str());
+        assert!(stats.chars < content.len());
+        assert_eq!(stats.headings, 1);
+    }
+
+    #[test]
+    fn count_words_excludes_frontmatter_and_code() {
+        let content = "---\ntitle: Stub\ntags: [a, b]\n---\n\n# Heading\n\nreal words here\n\n```\nnot counted at all\n```\n";
+        assert_eq!(count_words(content), 5);
+    }
+
+    #[test]
+    fn count_words_of_empty_or_stub_content() {
+        assert_eq!(count_words(""), 0);
+        assert_eq!(count_words("---\ntitle: x\n---\n"), 0);
+        assert_eq!(count_words("# Lone heading\n"), 3);
+    }
+
+    #[test]
+    fn words_per_section_tracks_levels_and_preamble() {
+        let content = "before any heading here\n\n# Intro\n\none two three\n\n## Detail\n\nfour five\n";
+        let sections = words_per_section(content);
+        assert_eq!(sections.len(), 3);
+        assert_eq!(sections[0].section, "(preamble)");
+        assert_eq!(sections[0].level, 0);
+        assert_eq!(sections[0].words, 4);
+        assert_eq!(sections[1].section, "Intro");
+        assert_eq!(sections[1].level, 1);
+        assert_eq!(sections[1].words, 3);
+        assert_eq!(sections[2].section, "Detail");
+        assert_eq!(sections[2].level, 2);
+        assert_eq!(sections[2].words, 2);
+    }
+
+    #[test]
+    fn empty_preamble_is_omitted() {
+        let sections = words_per_section("# Only\n\nwords here\n");
+        assert_eq!(sections.len(), 1);
+        assert_eq!(sections[0].section, "Only");
+    }
+
+    #[test]
+    fn diff_reports_growth() {
+        let old = compute_stats("one two\n");
+        let new = compute_stats("one two three four\nfive\n");
+        let diff = diff_stats(&old, &new);
+        assert_eq!(diff.words, 3);
+        assert_eq!(diff.lines, 1);
+        assert!(diff.chars > 0);
+    }
+
+    #[test]
+    fn diff_reports_shrinkage() {
+        let old = compute_stats("one two three\nfour\n");
+        let new = compute_stats("one\n");
+        let diff = diff_stats(&old, &new);
+        assert_eq!(diff.words, -3);
+        assert_eq!(diff.lines, -1);
+        assert!(diff.chars < 0);
+    }
+
+    #[test]
+    fn diff_of_identical_content_is_zero() {
+        let stats = compute_stats("same content\n");
+        let diff = diff_stats(&stats, &stats);
+        assert_eq!(diff, StatsDiff::default());
+        assert_eq!(diff.to_string(), "+0 words, +0 chars, +0 lines");
+    }
+
+    #[test]
+    fn empty_document_is_all_zeros() {
+        assert_eq!(compute_stats(""), DocStats::default());
+    }
+}
@@ -0,0 +1,183 @@
+//! Parsing of pipe-delimited markdown tables.
+
+use crate::markdown::code::{FenceEvent, FenceTracker};
+
+/// Column alignment from the separator row (`:---`, `:--:`, `---:`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Alignment {
+    /// No alignment specified (`---`).
+    #[default]
+    None,
+    Left,
+    Center,
+    Right,
+}
+
+impl Alignment {
+    /// The CSS `text-align` value, or `None` for unspecified columns.
+    pub fn css(&self) -> Option<&'static str> {
+        match self {
+            Alignment::None => None,
+            Alignment::Left => Some("left"),
+            Alignment::Center => Some("center"),
+            Alignment::Right => Some("right"),
+        }
+    }
+}
+
+/// A parsed markdown table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Table {
+    /// Header cells from the first row.
+    pub headers: Vec<String>,
+    /// Per-column alignment from the separator row.
+    pub alignments: Vec<Alignment>,
+    /// Body rows; each row has as many cells as there are headers
+    /// (short rows are padded with empty cells, long rows truncated).
+    pub rows: Vec<Vec<String>>,
+    /// 1-based line of the header row.
+    pub start_line: usize,
+    /// 1-based line of the last body row (or the separator when the
+    /// table has no body).
+    pub end_line: usize,
+}
+
+/// Splits a table line into trimmed cells, or `None` if the line does
+/// not contain a pipe.
+pub fn parse_table_row(line: &str) -> Option<Vec<String>> {
+    let trimmed = line.trim();
+    if !trimmed.contains('|') {
+        return None;
+    }
+    let inner = trimmed.strip_prefix('|').unwrap_or(trimmed);
+    let inner = inner.strip_suffix('|').unwrap_or(inner);
+    Some(inner.split('|').map(|cell| cell.trim().to_string()).collect())
+}
+
+/// Parses the separator row (`| --- | :--: |`), returning the per-column
+/// alignments, or `None` if the line isn't a valid separator.
+pub fn parse_separator(line: &str) -> Option<Vec<Alignment>> {
+    let cells = parse_table_row(line)?;
+    let mut alignments = Vec::with_capacity(cells.len());
+    for cell in &cells {
+        if cell.is_empty() || !cell.trim_matches(':').chars().all(|c| c == '-') {
+            return None;
+        }
+        if !cell.contains('-') {
+            return None;
+        }
+        let alignment = match (cell.starts_with(':'), cell.ends_with(':')) {
+            (true, true) => Alignment::Center,
+            (true, false) => Alignment::Left,
+            (false, true) => Alignment::Right,
+            (false, false) => Alignment::None,
+        };
+        alignments.push(alignment);
+    }
+    Some(alignments)
+}
+
+/// Finds every table in `content`: a row line immediately followed by a
+/// valid separator row, then any number of row lines. Lines inside code
+/// fences are never part of a table.
+pub fn parse_tables(content: &str) -> Vec<Table> {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut fence_outside = vec![false; lines.len()];
+    let mut tracker = FenceTracker::new();
+    for (index, line) in lines.iter().enumerate() {
+        fence_outside[index] = tracker.observe(line) == FenceEvent::Outside;
+    }
+
+    let mut tables = Vec::new();
+    let mut index = 0;
+    while index + 1 < lines.len() {
+        let header = fence_outside[index]
+            .then(|| parse_table_row(lines[index]))
+            .flatten();
+        let separator = fence_outside[index + 1]
+            .then(|| parse_separator(lines[index + 1]))
+            .flatten();
+        let (Some(headers), Some(alignments)) = (header, separator) else {
+            index += 1;
+            continue;
+        };
+        if headers.len() != alignments.len() {
+            index += 1;
+            continue;
+        }
+
+        let columns = headers.len();
+        let mut rows = Vec::new();
+        let mut end = index + 1;
+        while end + 1 < lines.len() && fence_outside[end + 1] {
+            let Some(mut cells) = parse_table_row(lines[end + 1]) else {
+                break;
+            };
+            cells.resize(columns, String::new());
+            rows.push(cells);
+            end += 1;
+        }
+
+        tables.push(Table {
+            headers,
+            alignments,
+            rows,
+            start_line: index + 1,
+            end_line: end + 1,
+        });
+        index = end + 1;
+    }
+    tables
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_simple_table() {
+        let content = "| Name | Count |\n| --- | ---: |\n| a | 1 |\n| b | 2 |\n";
+        let tables = parse_tables(content);
+        assert_eq!(tables.len(), 1);
+        let table = &tables[0];
+        assert_eq!(table.headers, vec!["Name", "Count"]);
+        assert_eq!(table.alignments, vec![Alignment::None, Alignment::Right]);
+        assert_eq!(table.rows, vec![vec!["a", "1"], vec!["b", "2"]]);
+        assert_eq!(table.start_line, 1);
+        assert_eq!(table.end_line, 4);
+    }
+
+    #[test]
+    fn separator_alignments_parse_each_form() {
+        let alignments = parse_separator("| :-- | :-: | --: | --- |").unwrap();
+        assert_eq!(
+            alignments,
+            vec![
+                Alignment::Left,
+                Alignment::Center,
+                Alignment::Right,
+                Alignment::None
+            ]
+        );
+        assert_eq!(parse_separator("| not | a separator |"), None);
+    }
+
+    #[test]
+    fn short_rows_are_padded_and_long_rows_truncated() {
+        let content = "| A | B |\n| --- | --- |\n| only |\n| x | y | extra |\n";
+        let table = &parse_tables(content)[0];
+        assert_eq!(table.rows[0], vec!["only", ""]);
+        assert_eq!(table.rows[1], vec!["x", "y"]);
+    }
+
+    #[test]
+    fn pipe_lines_inside_fences_are_not_tables() {
+        let content = "```\n| A | B |\n| --- | --- |\n```\n";
+        assert!(parse_tables(content).is_empty());
+    }
+
+    #[test]
+    fn mismatched_column_counts_are_not_a_table() {
+        assert!(parse_tables("| A | B |\n| --- |\n").is_empty());
+    }
+}
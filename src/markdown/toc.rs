@@ -0,0 +1,201 @@
+//! Headings, slugs, and table-of-contents extraction.
+
+use crate::markdown::code::{FenceEvent, FenceTracker};
+
+/// A heading found in a markdown document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Heading {
+    /// ATX level, 1 through 6.
+    pub level: usize,
+    /// Heading text without the `#` markers.
+    pub text: String,
+    /// 1-based line number of the heading.
+    pub line: usize,
+}
+
+/// Recognizes an ATX heading line, returning its level and text.
+pub(crate) fn parse_heading_line(line: &str) -> Option<(usize, &str)> {
+    let trimmed = line.trim_start();
+    let level = trimmed.chars().take_while(|&c| c == '#').count();
+    if (1..=6).contains(&level) {
+        let rest = &trimmed[level..];
+        if rest.is_empty() || rest.starts_with(' ') {
+            return Some((level, rest.trim()));
+        }
+    }
+    None
+}
+
+/// Extracts every heading outside code fences, in document order.
+pub fn extract_toc(content: &str) -> Vec<Heading> {
+    let mut headings = Vec::new();
+    let mut tracker = FenceTracker::new();
+
+    for (index, line) in content.lines().enumerate() {
+        if tracker.observe(line) != FenceEvent::Outside {
+            continue;
+        }
+        if let Some((level, text)) = parse_heading_line(line) {
+            headings.push(Heading {
+                level,
+                text: text.to_string(),
+                line: index + 1,
+            });
+        }
+    }
+    headings
+}
+
+/// Extracts the headings together with the line range of each section
+/// body.
+///
+/// Ranges are 1-based and half-open: they start on the line after the
+/// heading and end before the next heading of the same or a higher
+/// level, so a subsection's range is bounded by its parent's next
+/// sibling. The last section in scope runs to the end of the document.
+/// A heading immediately followed by its boundary has an empty range.
+pub fn toc_with_ranges(content: &str) -> Vec<(Heading, std::ops::Range<usize>)> {
+    let headings = extract_toc(content);
+    let end_of_document = content.lines().count() + 1;
+
+    headings
+        .iter()
+        .enumerate()
+        .map(|(index, heading)| {
+            let boundary = headings[index + 1..]
+                .iter()
+                .find(|next| next.level <= heading.level)
+                .map(|next| next.line)
+                .unwrap_or(end_of_document);
+            (heading.clone(), heading.line + 1..boundary)
+        })
+        .collect()
+}
+
+/// Derives a GitHub-style anchor slug from heading text: lower-cased,
+/// alphanumerics kept, spaces and hyphens become single hyphens, and
+/// everything else is dropped.
+pub fn slugify(text: &str) -> String {
+    let mut slug = String::with_capacity(text.len());
+    for c in text.to_lowercase().chars() {
+        if c.is_alphanumeric() {
+            slug.push(c);
+        } else if (c == ' ' || c == '-') && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.trim_matches('-').to_string()
+}
+
+/// Returns the slug for every heading, before any disambiguation.
+///
+/// Duplicate headings produce duplicate slugs here; disambiguation
+/// (appending `-1`, `-2`, ...) is left to consumers that need it.
+pub fn slugify_headings(content: &str) -> Vec<String> {
+    extract_toc(content)
+        .iter()
+        .map(|heading| slugify(&heading.text))
+        .collect()
+}
+
+/// The slugs that collide within `content`, with their occurrence counts.
+pub fn slug_collisions(content: &str) -> Vec<(String, usize)> {
+    let slugs = slugify_headings(content);
+    let mut seen: Vec<(String, usize)> = Vec::new();
+    for slug in slugs {
+        match seen.iter_mut().find(|(s, _)| *s == slug) {
+            Some((_, count)) => *count += 1,
+            None => seen.push((slug, 1)),
+        }
+    }
+    seen.retain(|(_, count)| *count > 1);
+    seen
+}
+
+/// Counts the headings whose slug collides with an earlier heading —
+/// i.e. the number of slugs that would need disambiguation. Three
+/// identical headings therefore count as two collisions.
+pub fn slug_collision_count(content: &str) -> usize {
+    slug_collisions(content)
+        .iter()
+        .map(|(_, count)| count - 1)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_headings_with_levels_and_lines() {
+        let toc = extract_toc("# One\n\ntext\n\n## Two\n");
+        assert_eq!(toc.len(), 2);
+        assert_eq!(toc[0], Heading { level: 1, text: "One".to_string(), line: 1 });
+        assert_eq!(toc[1], Heading { level: 2, text: "Two".to_string(), line: 5 });
+    }
+
+    #[test]
+    fn headings_inside_fences_are_ignored() {
+        let toc = extract_toc("# Real\n```\n# fake\n```\n");
+        assert_eq!(toc.len(), 1);
+        assert_eq!(toc[0].text, "Real");
+    }
+
+    #[test]
+    fn ranges_cover_three_sections_to_eof() {
+        let content = "# One\nbody one\n\n# Two\nbody two\n\n# Three\nbody three\nlast line\n";
+        let ranges = toc_with_ranges(content);
+        assert_eq!(ranges.len(), 3);
+        assert_eq!(ranges[0].0.text, "One");
+        assert_eq!(ranges[0].1, 2..4);
+        assert_eq!(ranges[1].1, 5..7);
+        // The final heading's section reaches the end of the document.
+        assert_eq!(ranges[2].1, 8..10);
+    }
+
+    #[test]
+    fn subsection_range_is_bounded_by_the_parents_next_sibling() {
+        let content = "# Parent\nintro\n## Child\nchild body\nmore child\n# Sibling\ntail\n";
+        let ranges = toc_with_ranges(content);
+        let child = &ranges[1];
+        assert_eq!(child.0.text, "Child");
+        assert_eq!(child.0.level, 2);
+        // The child's body stops where the parent's next sibling starts.
+        assert_eq!(child.1, 4..6);
+        // The parent's own range also ends at its sibling.
+        assert_eq!(ranges[0].1, 2..6);
+    }
+
+    #[test]
+    fn heading_with_no_body_has_an_empty_range() {
+        let ranges = toc_with_ranges("# Empty\n# Next\nbody\n");
+        assert_eq!(ranges[0].1, 2..2);
+        assert!(ranges[0].1.is_empty());
+        assert_eq!(ranges[1].1, 3..4);
+    }
+
+    #[test]
+    fn slugify_matches_anchor_conventions() {
+        assert_eq!(slugify("Getting Started"), "getting-started");
+        assert_eq!(slugify("FAQ & Tips!"), "faq-tips");
+        assert_eq!(slugify("Already-Hyphenated -- twice"), "already-hyphenated-twice");
+    }
+
+    #[test]
+    fn two_identical_headings_are_one_collision() {
+        let content = "# Setup\n\n# Setup\n";
+        assert_eq!(slug_collision_count(content), 1);
+        assert_eq!(slug_collisions(content), vec![("setup".to_string(), 2)]);
+    }
+
+    #[test]
+    fn three_identical_headings_are_two_collisions() {
+        assert_eq!(slug_collision_count("# A\n# A\n# A\n"), 2);
+    }
+
+    #[test]
+    fn distinct_headings_have_no_collisions() {
+        assert_eq!(slug_collision_count("# One\n## Two\n### Three\n"), 0);
+        assert!(slug_collisions("# One\n## Two\n").is_empty());
+    }
+}
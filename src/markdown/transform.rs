@@ -0,0 +1,131 @@
+//! Content transforms and document assembly.
+
+/// A text transform that can be applied to markdown content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transform {
+    /// Upper-case every character.
+    Uppercase,
+    /// Lower-case every character.
+    Lowercase,
+    /// Strip trailing whitespace from every line.
+    TrimTrailing,
+}
+
+impl Transform {
+    /// Parses a transform from its command-line name.
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "uppercase" => Some(Transform::Uppercase),
+            "lowercase" => Some(Transform::Lowercase),
+            "trim-trailing" => Some(Transform::TrimTrailing),
+            _ => None,
+        }
+    }
+
+    /// The command-line name of this transform.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Transform::Uppercase => "uppercase",
+            Transform::Lowercase => "lowercase",
+            Transform::TrimTrailing => "trim-trailing",
+        }
+    }
+
+    /// Applies the transform, returning the new content.
+    pub fn apply(&self, content: &str) -> String {
+        match self {
+            Transform::Uppercase => content.to_uppercase(),
+            Transform::Lowercase => content.to_lowercase(),
+            Transform::TrimTrailing => {
+                let mut out = String::with_capacity(content.len());
+                for line in content.lines() {
+                    out.push_str(line.trim_end());
+                    out.push('\n');
+                }
+                out
+            }
+        }
+    }
+}
+
+/// Applies each transform in order to `content`.
+pub fn apply_all(transforms: &[Transform], content: &str) -> String {
+    let mut current = content.to_string();
+    for transform in transforms {
+        current = transform.apply(&current);
+    }
+    current
+}
+
+/// Assembles the final document from optional prepend/append fragments.
+///
+/// Fragments are joined to the main content with exactly one blank line
+/// between them, regardless of trailing newlines in the pieces.
+/// Transforms are applied to the main content only, before composition,
+/// so a "generated" banner keeps its original casing.
+pub fn compose_document(prepend: Option<&str>, content: &str, append: Option<&str>) -> String {
+    let mut document = String::new();
+    if let Some(fragment) = prepend {
+        document.push_str(fragment.trim_end_matches('\n'));
+        document.push_str("\n\n");
+    }
+    document.push_str(content.trim_end_matches('\n'));
+    document.push('\n');
+    if let Some(fragment) = append {
+        document.push('\n');
+        document.push_str(fragment.trim_end_matches('\n'));
+        document.push('\n');
+    }
+    document
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uppercase_and_lowercase_round_trip() {
+        assert_eq!(Transform::Uppercase.apply("abc"), "ABC");
+        assert_eq!(Transform::Lowercase.apply("ABC"), "abc");
+    }
+
+    #[test]
+    fn trim_trailing_strips_line_ends() {
+        assert_eq!(Transform::TrimTrailing.apply("a  \nb\t\n"), "a\nb\n");
+    }
+
+    #[test]
+    fn apply_all_runs_in_order() {
+        let transforms = [Transform::Uppercase, Transform::Lowercase];
+        assert_eq!(apply_all(&transforms, "MiXeD"), "mixed");
+    }
+
+    #[test]
+    fn parse_rejects_unknown_names() {
+        assert_eq!(Transform::parse("uppercase"), Some(Transform::Uppercase));
+        assert_eq!(Transform::parse("rot13"), None);
+    }
+
+    #[test]
+    fn compose_prepend_only() {
+        let doc = compose_document(Some("header\n"), "body\n", None);
+        assert_eq!(doc, "header\n\nbody\n");
+    }
+
+    #[test]
+    fn compose_append_only() {
+        let doc = compose_document(None, "body\n", Some("footer\n"));
+        assert_eq!(doc, "body\n\nfooter\n");
+    }
+
+    #[test]
+    fn compose_both_with_single_blank_separators() {
+        let doc = compose_document(Some("header\n\n\n"), "body", Some("footer"));
+        assert_eq!(doc, "header\n\nbody\n\nfooter\n");
+    }
+
+    #[test]
+    fn compose_without_fragments_is_identity_plus_newline() {
+        assert_eq!(compose_document(None, "body", None), "body\n");
+    }
+}
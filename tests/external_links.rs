@@ -0,0 +1,70 @@
+//! Network-facing tests for the external link checker.
+//!
+//! These spin up a local mock HTTP server and are `#[ignore]`d so normal
+//! test runs stay offline. Run them with:
+//! `cargo test --features external-links -- --ignored`
+
+#![cfg(feature = "external-links")]
+
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::time::Duration;
+
+use ai_coding_agent::markdown::links::{check_external_links, ExternalStatus};
+
+/// Serves exactly one request with the given status line, returning the
+/// bound address.
+fn mock_server(status_line: &'static str) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+    std::thread::spawn(move || {
+        if let Ok((mut stream, _)) = listener.accept() {
+            // Drain the request headers before answering.
+            let mut buf = [0u8; 1024];
+            let mut request = Vec::new();
+            loop {
+                match stream.read(&mut buf) {
+                    Ok(0) | Err(_) => break,
+                    Ok(n) => {
+                        request.extend_from_slice(&buf[..n]);
+                        if request.windows(4).any(|window| window == b"\r\n\r\n") {
+                            break;
+                        }
+                    }
+                }
+            }
+            write!(stream, "{status_line}\r\nContent-Length: 0\r\n\r\n").ok();
+        }
+    });
+    address
+}
+
+#[test]
+#[ignore = "binds a local socket"]
+fn ok_status_from_local_server() {
+    let address = mock_server("HTTP/1.1 200 OK");
+    let content = format!("[local](http://{address}/page)\n");
+    let results = check_external_links(&content, Duration::from_secs(2));
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].1, ExternalStatus::Status(200));
+    assert!(results[0].1.is_ok());
+}
+
+#[test]
+#[ignore = "binds a local socket"]
+fn server_error_status_is_not_ok() {
+    let address = mock_server("HTTP/1.1 500 Internal Server Error");
+    let content = format!("[local](http://{address}/broken)\n");
+    let results = check_external_links(&content, Duration::from_secs(2));
+    assert_eq!(results[0].1, ExternalStatus::Status(500));
+    assert!(!results[0].1.is_ok());
+}
+
+#[test]
+#[ignore = "exercises the connect-failure path"]
+fn unreachable_server_reports_an_error() {
+    // Port 1 on localhost is almost certainly closed.
+    let results =
+        check_external_links("[dead](http://127.0.0.1:1/)\n", Duration::from_millis(500));
+    assert!(matches!(results[0].1, ExternalStatus::Error(_)));
+}